@@ -0,0 +1,15 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use lolid::Uuid;
+
+fn parse(criterion: &mut Criterion) {
+    let hyphenated = "6ba7b810-9dad-11d1-80b4-00c04fd430c8";
+    let simple = "6ba7b8109dad11d180b400c04fd430c8";
+
+    criterion.bench_function("parse_str hyphenated", |bencher| bencher.iter(|| Uuid::parse_str(black_box(hyphenated))));
+    criterion.bench_function("parse_str simple", |bencher| bencher.iter(|| Uuid::parse_str(black_box(simple))));
+    criterion.bench_function("to_str", |bencher| bencher.iter(|| black_box(lolid::NAMESPACE_DNS).to_str()));
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
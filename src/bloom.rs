@@ -0,0 +1,141 @@
+//!Bloom filter for `Uuid`.
+
+use crate::Uuid;
+
+#[inline(always)]
+//UUID bits are used as hashes directly, assuming they are random enough (e.g. `v4` or `v7`)
+const fn hashes(uuid: &Uuid) -> (u64, u64) {
+    let value = u128::from_be_bytes(uuid.data);
+    let high = (value >> 64) as u64;
+    let low = value as u64;
+    (high ^ low, low.rotate_left(32))
+}
+
+#[cfg(feature = "alloc")]
+//Approximates log2(1 / rate) without relying on `std`.
+fn log2_inverse(rate: f64) -> f64 {
+    let value = 1.0 / rate;
+    let bits = value.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i64 - 1023;
+    let mantissa = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | 0x3ff0_0000_0000_0000) - 1.0;
+    //log2(1 + m) ~ m * (1.3465 - 0.3465 * m), with error below 0.01
+    exponent as f64 + mantissa * (1.3465 - 0.3465 * mantissa)
+}
+
+#[derive(Clone, Debug)]
+///Bloom filter for `Uuid`, answering whether UUID is probably present or definitely absent.
+///
+///Bits of UUID are used as hashes directly, without extra hashing, hence it is only suitable for
+///UUIDs with enough randomness (e.g. `v4` or `v7`).
+///
+///Storage is provided by user, so it can be used without allocator.
+pub struct BloomFilter<S> {
+    bits: S,
+    hashes: u32,
+}
+
+impl<S: AsRef<[u64]>> BloomFilter<S> {
+    ///Creates filter using `bits` as storage, setting `hashes` bits per UUID.
+    ///
+    ///Storage is expected to be zeroed, unless it is previously filled filter.
+    ///
+    ///## Panics
+    ///
+    ///If `bits` is empty or `hashes` is zero.
+    pub fn from_storage(bits: S, hashes: u32) -> Self {
+        assert!(!bits.as_ref().is_empty(), "Bloom filter storage cannot be empty");
+        assert_ne!(hashes, 0, "Bloom filter requires at least one hash");
+
+        Self {
+            bits,
+            hashes,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns number of bits set per UUID.
+    pub fn hashes(&self) -> u32 {
+        self.hashes
+    }
+
+    #[inline(always)]
+    ///Returns number of bits in the filter.
+    pub fn bits(&self) -> usize {
+        self.bits.as_ref().len() * 64
+    }
+
+    #[inline(always)]
+    ///Returns underlying storage.
+    pub fn into_inner(self) -> S {
+        self.bits
+    }
+
+    #[inline(always)]
+    //Double hashing, where n-th bit is `(first + n * second) mod bits`.
+    //Bits are distinct only if step is coprime with number of bits, otherwise some of them may
+    //repeat, slightly increasing false positive rate.
+    fn positions(&self, uuid: &Uuid) -> impl Iterator<Item = (usize, u64)> {
+        let bits = self.bits() as u64;
+        let (first, second) = hashes(uuid);
+        let step = match second % bits {
+            0 => 1,
+            step => step,
+        };
+        let mut bit = first % bits;
+        (0..self.hashes).map(move |_| {
+            let result = ((bit / 64) as usize, 1 << (bit % 64));
+            bit = ((bit as u128 + step as u128) % bits as u128) as u64;
+            result
+        })
+    }
+
+    #[inline]
+    ///Returns `true` if `uuid` is probably present, and `false` if it is definitely absent.
+    pub fn contains(&self, uuid: &Uuid) -> bool {
+        let bits = self.bits.as_ref();
+        self.positions(uuid).all(|(word, mask)| bits[word] & mask != 0)
+    }
+}
+
+impl<S: AsRef<[u64]> + AsMut<[u64]>> BloomFilter<S> {
+    ///Adds `uuid` to the filter, returning whether it was probably present before.
+    pub fn insert(&mut self, uuid: &Uuid) -> bool {
+        let mut result = true;
+        for (word, mask) in self.positions(uuid) {
+            let word = &mut self.bits.as_mut()[word];
+            result &= *word & mask != 0;
+            *word |= mask;
+        }
+        result
+    }
+
+    #[inline]
+    ///Removes all UUIDs from the filter.
+    pub fn clear(&mut self) {
+        for word in self.bits.as_mut().iter_mut() {
+            *word = 0;
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl BloomFilter<alloc::vec::Vec<u64>> {
+    ///Creates filter, sized to hold `expected` number of UUIDs with specified `false_positive_rate`.
+    ///
+    ///Only available when `alloc` feature is enabled.
+    ///
+    ///## Panics
+    ///
+    ///If `false_positive_rate` is not within `(0, 1)`.
+    pub fn with_rate(expected: usize, false_positive_rate: f64) -> Self {
+        assert!(false_positive_rate > 0.0 && false_positive_rate < 1.0, "False positive rate must be within (0, 1)");
+
+        let log2_rate = log2_inverse(false_positive_rate);
+        //Optimal number of bits is n * log2(1 / p) / ln(2), while optimal number of hashes is log2(1 / p).
+        let bits = (core::cmp::max(expected, 1) as f64 * log2_rate * core::f64::consts::LOG2_E) as usize;
+        let words = core::cmp::max(bits.saturating_add(63) / 64, 1);
+        let hashes = core::cmp::max((log2_rate + 0.5) as u32, 1);
+
+        Self::from_storage(alloc::vec![0; words], hashes)
+    }
+}
@@ -0,0 +1,142 @@
+//!ClickHouse `UUID` support.
+//!
+//!ClickHouse stores `UUID` as two little endian `u64` halves in its binary formats,
+//!hence `Uuid` requires dedicated serialization when used with `clickhouse` crate's `Row`.
+//!
+//!## Usage
+//!
+//!```rust,ignore
+//!#[derive(clickhouse::Row, serde::Serialize, serde::Deserialize)]
+//!struct Event {
+//!    #[serde(with = "lolid::clickhouse")]
+//!    id: lolid::Uuid,
+//!    #[serde(with = "lolid::clickhouse::option")]
+//!    parent: Option<lolid::Uuid>,
+//!}
+//!```
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+use crate::Uuid;
+
+#[inline]
+///Serializes `Uuid` in ClickHouse format.
+///
+///Human readable formats use textual representation.
+pub fn serialize<SER: Serializer>(uuid: &Uuid, ser: SER) -> Result<SER::Ok, SER::Error> {
+    if ser.is_human_readable() {
+        uuid.serialize(ser)
+    } else {
+        let value = u128::from(*uuid);
+        ((value >> 64) as u64, value as u64).serialize(ser)
+    }
+}
+
+#[inline]
+///Deserializes `Uuid` from ClickHouse format.
+///
+///Human readable formats use textual representation.
+pub fn deserialize<'de, D: Deserializer<'de>>(des: D) -> Result<Uuid, D::Error> {
+    if des.is_human_readable() {
+        Uuid::deserialize(des)
+    } else {
+        let (high, low) = <(u64, u64)>::deserialize(des)?;
+        Ok(Uuid::from_bytes((((high as u128) << 64) | low as u128).to_be_bytes()))
+    }
+}
+
+///ClickHouse `Nullable(UUID)` support.
+pub mod option {
+    use serde::de::{Deserialize, Deserializer};
+    use serde::ser::Serializer;
+
+    use crate::Uuid;
+
+    struct Wrapper(Uuid);
+
+    impl<'de> Deserialize<'de> for Wrapper {
+        #[inline(always)]
+        fn deserialize<D: Deserializer<'de>>(des: D) -> Result<Self, D::Error> {
+            super::deserialize(des).map(Wrapper)
+        }
+    }
+
+    #[inline]
+    ///Serializes optional `Uuid` in ClickHouse format.
+    pub fn serialize<SER: Serializer>(uuid: &Option<Uuid>, ser: SER) -> Result<SER::Ok, SER::Error> {
+        match uuid {
+            Some(uuid) => ser.serialize_some(&Wrapper(*uuid)),
+            None => ser.serialize_none(),
+        }
+    }
+
+    impl serde::ser::Serialize for Wrapper {
+        #[inline(always)]
+        fn serialize<SER: Serializer>(&self, ser: SER) -> Result<SER::Ok, SER::Error> {
+            super::serialize(&self.0, ser)
+        }
+    }
+
+    #[inline]
+    ///Deserializes optional `Uuid` from ClickHouse format.
+    pub fn deserialize<'de, D: Deserializer<'de>>(des: D) -> Result<Option<Uuid>, D::Error> {
+        Option::<Wrapper>::deserialize(des).map(|uuid| uuid.map(|uuid| uuid.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Uuid;
+
+    use serde::de::{Deserialize, Deserializer};
+    use serde::ser::{Serialize, Serializer};
+
+    #[derive(PartialEq, Debug)]
+    struct Column(Uuid);
+
+    impl Serialize for Column {
+        fn serialize<SER: Serializer>(&self, ser: SER) -> Result<SER::Ok, SER::Error> {
+            crate::clickhouse::serialize(&self.0, ser)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Column {
+        fn deserialize<D: Deserializer<'de>>(des: D) -> Result<Self, D::Error> {
+            crate::clickhouse::deserialize(des).map(Column)
+        }
+    }
+
+    #[derive(PartialEq, Debug)]
+    struct NullableColumn(Option<Uuid>);
+
+    impl Serialize for NullableColumn {
+        fn serialize<SER: Serializer>(&self, ser: SER) -> Result<SER::Ok, SER::Error> {
+            crate::clickhouse::option::serialize(&self.0, ser)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for NullableColumn {
+        fn deserialize<D: Deserializer<'de>>(des: D) -> Result<Self, D::Error> {
+            crate::clickhouse::option::deserialize(des).map(NullableColumn)
+        }
+    }
+
+    #[test]
+    fn should_serialize_halves_swapped() {
+        let uuid = Uuid::parse_str("61f0c404-5cb3-11e7-907b-a6006ad3dba0").unwrap();
+        let expected = [0xe7, 0x11, 0xb3, 0x5c, 0x04, 0xc4, 0xf0, 0x61, 0xa0, 0xdb, 0xd3, 0x6a, 0x00, 0xa6, 0x7b, 0x90];
+
+        let serialized = bincode::serialize(&Column(uuid)).unwrap();
+        assert_eq!(serialized, expected);
+        assert_eq!(bincode::deserialize::<Column>(&serialized).unwrap(), Column(uuid));
+
+        let serialized = bincode::serialize(&NullableColumn(Some(uuid))).unwrap();
+        assert_eq!(serialized[0], 1);
+        assert_eq!(serialized[1..], expected);
+        assert_eq!(bincode::deserialize::<NullableColumn>(&serialized).unwrap(), NullableColumn(Some(uuid)));
+
+        let serialized = bincode::serialize(&NullableColumn(None)).unwrap();
+        assert_eq!(bincode::deserialize::<NullableColumn>(&serialized).unwrap(), NullableColumn(None));
+    }
+}
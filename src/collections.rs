@@ -0,0 +1,447 @@
+//!Collections specialized for `Uuid` keys.
+//!
+//!Only available when `collections` feature is enabled.
+
+use alloc::vec::Vec;
+use core::{fmt, mem};
+
+use crate::Uuid;
+
+const GROUP_WIDTH: usize = 8;
+const EMPTY: u8 = 0b1111_1111;
+const DELETED: u8 = 0b1000_0000;
+const LO_BITS: u64 = 0x0101_0101_0101_0101;
+const HI_BITS: u64 = 0x8080_8080_8080_8080;
+
+#[inline(always)]
+//UUIDs are expected to be mostly random already, so folding halves is enough to get hash.
+const fn fold(key: &Uuid) -> u64 {
+    let value = u128::from_be_bytes(key.data);
+    (value >> 64) as u64 ^ value as u64
+}
+
+#[inline(always)]
+const fn tag(hash: u64) -> u8 {
+    (hash >> 57) as u8
+}
+
+#[inline(always)]
+const fn max_len(buckets: usize) -> usize {
+    buckets / 8 * 7
+}
+
+#[derive(Clone, Copy)]
+//Control bytes of group, matched all at once using SWAR.
+struct Group(u64);
+
+impl Group {
+    #[inline(always)]
+    fn load(ctrl: &[u8], start: usize) -> Self {
+        let mut bytes = [0u8; GROUP_WIDTH];
+        bytes.copy_from_slice(&ctrl[start..start + GROUP_WIDTH]);
+        Self(u64::from_le_bytes(bytes))
+    }
+
+    #[inline(always)]
+    //Can have false positives, which are filtered out by comparing keys.
+    fn match_tag(self, tag: u8) -> BitMask {
+        let cmp = self.0 ^ (LO_BITS * tag as u64);
+        BitMask(cmp.wrapping_sub(LO_BITS) & !cmp & HI_BITS)
+    }
+
+    #[inline(always)]
+    fn match_empty(self) -> BitMask {
+        BitMask(self.0 & (self.0 << 1) & HI_BITS)
+    }
+
+    #[inline(always)]
+    fn match_empty_or_deleted(self) -> BitMask {
+        BitMask(self.0 & HI_BITS)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct BitMask(u64);
+
+impl Iterator for BitMask {
+    type Item = usize;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            None
+        } else {
+            let idx = self.0.trailing_zeros() as usize / 8;
+            self.0 &= self.0 - 1;
+            Some(idx)
+        }
+    }
+}
+
+#[derive(Clone)]
+///Open addressing hash map with `Uuid` keys.
+///
+///Keys are stored inline, and used as hash as they are, without re-hashing, assuming they are already
+///random enough (e.g. `v4` or `v7`).
+///Probing is performed over groups of 8 slots at once.
+pub struct UuidMap<T> {
+    ctrl: Vec<u8>,
+    keys: Vec<Uuid>,
+    values: Vec<Option<T>>,
+    len: usize,
+    growth_left: usize,
+}
+
+impl<T> UuidMap<T> {
+    #[inline]
+    ///Creates new empty map, which allocates on first insert.
+    pub const fn new() -> Self {
+        Self {
+            ctrl: Vec::new(),
+            keys: Vec::new(),
+            values: Vec::new(),
+            len: 0,
+            growth_left: 0,
+        }
+    }
+
+    ///Creates new map, able to hold at least `capacity` elements without reallocation.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut result = Self::new();
+        if capacity > 0 {
+            result.resize(capacity);
+        }
+        result
+    }
+
+    #[inline(always)]
+    ///Returns number of elements in the map.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    ///Returns whether map is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline(always)]
+    ///Returns number of elements map can hold without reallocation.
+    pub fn capacity(&self) -> usize {
+        max_len(self.ctrl.len())
+    }
+
+    #[inline(always)]
+    fn group_mask(&self) -> usize {
+        self.ctrl.len() / GROUP_WIDTH - 1
+    }
+
+    fn find(&self, key: &Uuid) -> Option<usize> {
+        if self.ctrl.is_empty() {
+            return None;
+        }
+
+        let hash = fold(key);
+        let tag = tag(hash);
+        let mask = self.group_mask();
+        let mut group_idx = hash as usize & mask;
+        let mut stride = 0;
+        loop {
+            let start = group_idx * GROUP_WIDTH;
+            let group = Group::load(&self.ctrl, start);
+            for idx in group.match_tag(tag) {
+                if self.ctrl[start + idx] == tag && self.keys[start + idx] == *key {
+                    return Some(start + idx);
+                }
+            }
+            if group.match_empty().0 != 0 {
+                return None;
+            }
+
+            stride += 1;
+            group_idx = (group_idx + stride) & mask;
+        }
+    }
+
+    //Finds slot for new key, which is known to be absent.
+    fn find_insert_slot(&self, hash: u64) -> usize {
+        let mask = self.group_mask();
+        let mut group_idx = hash as usize & mask;
+        let mut stride = 0;
+        loop {
+            let start = group_idx * GROUP_WIDTH;
+            if let Some(idx) = Group::load(&self.ctrl, start).match_empty_or_deleted().next() {
+                return start + idx;
+            }
+
+            stride += 1;
+            group_idx = (group_idx + stride) & mask;
+        }
+    }
+
+    fn resize(&mut self, capacity: usize) {
+        let buckets = match capacity.checked_mul(8) {
+            Some(capacity) => core::cmp::max((capacity / 7).next_power_of_two(), GROUP_WIDTH),
+            None => panic!("Capacity overflow"),
+        };
+
+        let ctrl = mem::replace(&mut self.ctrl, alloc::vec![EMPTY; buckets]);
+        let keys = mem::replace(&mut self.keys, alloc::vec![Uuid::nil(); buckets]);
+        let values = mem::replace(&mut self.values, Vec::with_capacity(buckets));
+        self.values.resize_with(buckets, || None);
+        self.growth_left = max_len(buckets) - self.len;
+
+        for ((ctrl, key), value) in ctrl.into_iter().zip(keys).zip(values) {
+            if ctrl & DELETED == 0 {
+                let hash = fold(&key);
+                let idx = self.find_insert_slot(hash);
+                self.ctrl[idx] = tag(hash);
+                self.keys[idx] = key;
+                self.values[idx] = value;
+            }
+        }
+    }
+
+    ///Inserts `value` for `key`, returning previous value, if any.
+    pub fn insert(&mut self, key: Uuid, value: T) -> Option<T> {
+        if let Some(idx) = self.find(&key) {
+            return self.values[idx].replace(value);
+        }
+
+        if self.growth_left == 0 {
+            self.resize(self.len + 1);
+        }
+
+        let hash = fold(&key);
+        let idx = self.find_insert_slot(hash);
+        if self.ctrl[idx] == EMPTY {
+            self.growth_left -= 1;
+        }
+        self.ctrl[idx] = tag(hash);
+        self.keys[idx] = key;
+        self.values[idx] = Some(value);
+        self.len += 1;
+        None
+    }
+
+    #[inline]
+    ///Returns reference to value of `key`, if any.
+    pub fn get(&self, key: &Uuid) -> Option<&T> {
+        match self.find(key) {
+            Some(idx) => self.values[idx].as_ref(),
+            None => None,
+        }
+    }
+
+    #[inline]
+    ///Returns mutable reference to value of `key`, if any.
+    pub fn get_mut(&mut self, key: &Uuid) -> Option<&mut T> {
+        match self.find(key) {
+            Some(idx) => self.values[idx].as_mut(),
+            None => None,
+        }
+    }
+
+    #[inline]
+    ///Returns whether map contains `key`.
+    pub fn contains_key(&self, key: &Uuid) -> bool {
+        self.find(key).is_some()
+    }
+
+    ///Removes `key`, returning its value, if any.
+    pub fn remove(&mut self, key: &Uuid) -> Option<T> {
+        let idx = self.find(key)?;
+        self.ctrl[idx] = DELETED;
+        self.len -= 1;
+        self.values[idx].take()
+    }
+
+    ///Reserves capacity for at least `additional` more elements.
+    pub fn reserve(&mut self, additional: usize) {
+        if additional > self.growth_left {
+            match self.len.checked_add(additional) {
+                Some(capacity) => self.resize(capacity),
+                None => panic!("Capacity overflow"),
+            }
+        }
+    }
+
+    ///Removes all elements, keeping allocated memory.
+    pub fn clear(&mut self) {
+        for ctrl in self.ctrl.iter_mut() {
+            *ctrl = EMPTY;
+        }
+        for value in self.values.iter_mut() {
+            *value = None;
+        }
+        self.len = 0;
+        self.growth_left = max_len(self.ctrl.len());
+    }
+
+    #[inline]
+    ///Returns iterator over keys and values in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Uuid, &T)> {
+        self.keys.iter().zip(self.values.iter()).filter_map(|(key, value)| value.as_ref().map(|value| (key, value)))
+    }
+
+    #[inline]
+    ///Returns iterator over keys in arbitrary order.
+    pub fn keys(&self) -> impl Iterator<Item = &Uuid> {
+        self.iter().map(|(key, _)| key)
+    }
+}
+
+impl<T> Default for UuidMap<T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for UuidMap<T> {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Extend<(Uuid, T)> for UuidMap<T> {
+    fn extend<I: IntoIterator<Item = (Uuid, T)>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<T> core::iter::FromIterator<(Uuid, T)> for UuidMap<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (Uuid, T)>>(iter: I) -> Self {
+        let mut result = Self::new();
+        result.extend(iter);
+        result
+    }
+}
+
+#[derive(Clone, Default)]
+///Set of `Uuid`, built on top of [UuidMap](struct.UuidMap.html).
+pub struct UuidSet {
+    map: UuidMap<()>,
+}
+
+impl UuidSet {
+    #[inline]
+    ///Creates new empty set, which allocates on first insert.
+    pub const fn new() -> Self {
+        Self {
+            map: UuidMap::new(),
+        }
+    }
+
+    #[inline]
+    ///Creates new set, able to hold at least `capacity` elements without reallocation.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            map: UuidMap::with_capacity(capacity),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns number of elements in the set.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    #[inline(always)]
+    ///Returns whether set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    #[inline(always)]
+    ///Returns number of elements set can hold without reallocation.
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    #[inline(always)]
+    ///Reserves capacity for at least `additional` more elements.
+    pub fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional)
+    }
+
+    #[inline]
+    ///Adds `uuid` to the set, returning `true` if it was not present.
+    pub fn insert(&mut self, uuid: Uuid) -> bool {
+        self.map.insert(uuid, ()).is_none()
+    }
+
+    #[inline]
+    ///Returns whether set contains `uuid`.
+    pub fn contains(&self, uuid: &Uuid) -> bool {
+        self.map.contains_key(uuid)
+    }
+
+    #[inline]
+    ///Removes `uuid` from the set, returning `true` if it was present.
+    pub fn remove(&mut self, uuid: &Uuid) -> bool {
+        self.map.remove(uuid).is_some()
+    }
+
+    #[inline(always)]
+    ///Removes all elements, keeping allocated memory.
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+
+    #[inline]
+    ///Returns iterator over elements in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = &Uuid> {
+        self.map.keys()
+    }
+
+    ///Creates set with elements present in both `self` and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (small, big) = match self.len() <= other.len() {
+            true => (self, other),
+            false => (other, self),
+        };
+        small.iter().filter(|uuid| big.contains(uuid)).copied().collect()
+    }
+
+    ///Creates set with elements present in either `self` or `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        let (small, big) = match self.len() <= other.len() {
+            true => (self, other),
+            false => (other, self),
+        };
+        let mut result = big.clone();
+        result.extend(small.iter().copied());
+        result
+    }
+}
+
+impl fmt::Debug for UuidSet {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl Extend<Uuid> for UuidSet {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Uuid>>(&mut self, iter: I) {
+        self.map.extend(iter.into_iter().map(|uuid| (uuid, ())))
+    }
+}
+
+impl core::iter::FromIterator<Uuid> for UuidSet {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Uuid>>(iter: I) -> Self {
+        let mut result = Self::new();
+        result.extend(iter);
+        result
+    }
+}
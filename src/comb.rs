@@ -0,0 +1,94 @@
+//!COMB UUIDs, suitable for SQL Server `uniqueidentifier` columns.
+//!
+//!SQL Server compares last 6 bytes of `uniqueidentifier` first, hence COMB places timestamp there,
+//!while the rest of UUID is random `v4`.
+//!This keeps inserts close to each other within index, avoiding its fragmentation.
+//!
+//!Two layouts are provided:
+//!
+//!- Millisecond, storing 48 bits of unix timestamp in milliseconds;
+//!- Datetime, storing SQL Server `datetime` as 2 bytes of days since 1900-01-01 and 4 bytes of
+//!  1/300 second intervals since midnight, as per original COMB algorithm.
+
+use core::time;
+
+use crate::Uuid;
+
+//Number of days between 1900-01-01 and 1970-01-01
+const DATETIME_UNIX_DAYS: u64 = 25_567;
+const SECS_PER_DAY: u64 = 86_400;
+
+#[inline(always)]
+const fn with_timestamp(rand: [u8; 10], time: [u8; 6]) -> Uuid {
+    Uuid::v4_from([
+        rand[0], rand[1], rand[2], rand[3], rand[4], rand[5], rand[6], rand[7], rand[8], rand[9],
+        time[0], time[1], time[2], time[3], time[4], time[5],
+    ])
+}
+
+///Constructs COMB UUID from unix `time`, storing it in milliseconds.
+///
+///Only lower 48 bits of milliseconds are used.
+///It is up to user to guarantee that `rand` is random.
+pub const fn from_parts(time: time::Duration, rand: [u8; 10]) -> Uuid {
+    let time = (time.as_millis() as u64).to_be_bytes();
+    with_timestamp(rand, [time[2], time[3], time[4], time[5], time[6], time[7]])
+}
+
+///Constructs COMB UUID from unix `time`, storing it as SQL Server `datetime`.
+///
+///Days are stored as 16 bit integer, hence they wrap after 2079-06-06.
+///It is up to user to guarantee that `rand` is random.
+pub const fn from_parts_datetime(time: time::Duration, rand: [u8; 10]) -> Uuid {
+    let secs = time.as_secs();
+    let days = (DATETIME_UNIX_DAYS + secs / SECS_PER_DAY) as u16;
+    let ticks = ((secs % SECS_PER_DAY) * 300 + (time.subsec_nanos() as u64 * 3) / 10_000_000) as u32;
+
+    let days = days.to_be_bytes();
+    let ticks = ticks.to_be_bytes();
+    with_timestamp(rand, [days[0], days[1], ticks[0], ticks[1], ticks[2], ticks[3]])
+}
+
+#[cfg(any(feature = "osrng", feature = "prng"))]
+///Generates COMB UUID from unix `time`, storing it in milliseconds.
+///
+///Uses OS RNG when `osrng` is enabled, otherwise PRNG.
+///
+///Only available when either `osrng` or `prng` feature is enabled.
+pub fn new(time: time::Duration) -> Uuid {
+    let rand = crate::random_bytes();
+    from_parts(time, [rand[0], rand[1], rand[2], rand[3], rand[4], rand[5], rand[6], rand[7], rand[8], rand[9]])
+}
+
+#[cfg(any(feature = "osrng", feature = "prng"))]
+///Generates COMB UUID from unix `time`, storing it as SQL Server `datetime`.
+///
+///Uses OS RNG when `osrng` is enabled, otherwise PRNG.
+///
+///Only available when either `osrng` or `prng` feature is enabled.
+pub fn new_datetime(time: time::Duration) -> Uuid {
+    let rand = crate::random_bytes();
+    from_parts_datetime(time, [rand[0], rand[1], rand[2], rand[3], rand[4], rand[5], rand[6], rand[7], rand[8], rand[9]])
+}
+
+#[cfg(all(feature = "std", any(feature = "osrng", feature = "prng")))]
+///Generates COMB UUID using current time, storing it in milliseconds.
+///
+///Only available when `std` and either `osrng` or `prng` feature is enabled.
+pub fn now() -> Uuid {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).expect("System time is behind unix epoch");
+    new(now)
+}
+
+#[cfg(all(feature = "std", any(feature = "osrng", feature = "prng")))]
+///Generates COMB UUID using current time, storing it as SQL Server `datetime`.
+///
+///Only available when `std` and either `osrng` or `prng` feature is enabled.
+pub fn now_datetime() -> Uuid {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).expect("System time is behind unix epoch");
+    new_datetime(now)
+}
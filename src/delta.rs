@@ -0,0 +1,162 @@
+//!Delta compression of sorted `v7` UUIDs.
+//!
+//!Each UUID is encoded as varint of its timestamp difference to the previous UUID, followed by
+//!remaining 10 bytes of UUID as they are.
+//!Hence closely generated UUIDs take 11-12 bytes instead of 16.
+//!
+//!Encoding relies only on first 48 bits being millisecond timestamp, and preserves the rest as it is.
+
+use core::fmt;
+
+use crate::{UUID_SIZE, Uuid};
+
+const TIMESTAMP_SIZE: usize = 6;
+const RESIDUAL_SIZE: usize = UUID_SIZE - TIMESTAMP_SIZE;
+const MAX_TIMESTAMP: u64 = 0xFFFF_FFFF_FFFF;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+///Error happening when delta encoding or decoding fails.
+pub enum DeltaError {
+    ///UUID at specified index has lower timestamp than previous one.
+    Unsorted(usize),
+    ///Input ends in the middle of UUID.
+    Truncated,
+    ///Timestamp doesn't fit 48 bits.
+    Overflow,
+}
+
+impl fmt::Display for DeltaError {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeltaError::Unsorted(idx) => fmt.write_fmt(format_args!("UUID at index {} is not sorted", idx)),
+            DeltaError::Truncated => fmt.write_str("Input is truncated"),
+            DeltaError::Overflow => fmt.write_str("Timestamp overflow"),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[inline(always)]
+const fn timestamp(uuid: &Uuid) -> u64 {
+    let data = &uuid.data;
+    u64::from_be_bytes([0, 0, data[0], data[1], data[2], data[3], data[4], data[5]])
+}
+
+#[cfg(feature = "alloc")]
+///Appends delta encoded `uuids` to `out`.
+///
+///Only available when `alloc` feature is enabled.
+///
+///`uuids` must be sorted by timestamp, otherwise error is returned and `out` is left unchanged.
+pub fn encode(uuids: &[Uuid], out: &mut alloc::vec::Vec<u8>) -> Result<(), DeltaError> {
+    let mut prev = 0;
+    for (idx, uuid) in uuids.iter().enumerate() {
+        if timestamp(uuid) < prev {
+            return Err(DeltaError::Unsorted(idx));
+        }
+        prev = timestamp(uuid);
+    }
+
+    out.reserve(uuids.len() * (RESIDUAL_SIZE + 2));
+    let mut prev = 0;
+    for uuid in uuids {
+        let mut delta = timestamp(uuid) - prev;
+        prev = timestamp(uuid);
+
+        while delta >= 0x80 {
+            out.push(delta as u8 | 0x80);
+            delta >>= 7;
+        }
+        out.push(delta as u8);
+        out.extend_from_slice(&uuid.data[TIMESTAMP_SIZE..]);
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Debug)]
+///Iterator over UUIDs decoded from delta encoded input.
+///
+///Stops after first error.
+pub struct Decoder<'a> {
+    input: &'a [u8],
+    prev: u64,
+}
+
+impl<'a> Decoder<'a> {
+    #[inline(always)]
+    ///Creates new decoder over `input`.
+    pub const fn new(input: &'a [u8]) -> Self {
+        Self {
+            input,
+            prev: 0,
+        }
+    }
+
+    fn decode_next(&mut self) -> Result<Uuid, DeltaError> {
+        let mut delta = 0u64;
+        let mut shift = 0;
+        loop {
+            let (byte, rest) = match self.input.split_first() {
+                Some((byte, rest)) => (*byte, rest),
+                None => return Err(DeltaError::Truncated),
+            };
+            self.input = rest;
+
+            if shift >= 49 {
+                return Err(DeltaError::Overflow);
+            }
+            delta |= ((byte & 0x7f) as u64) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+
+        let timestamp = match self.prev.checked_add(delta) {
+            Some(timestamp) if timestamp <= MAX_TIMESTAMP => timestamp,
+            _ => return Err(DeltaError::Overflow),
+        };
+        if self.input.len() < RESIDUAL_SIZE {
+            return Err(DeltaError::Truncated);
+        }
+        let (residual, rest) = self.input.split_at(RESIDUAL_SIZE);
+        self.input = rest;
+        self.prev = timestamp;
+
+        let mut data = [0u8; UUID_SIZE];
+        data[..TIMESTAMP_SIZE].copy_from_slice(&timestamp.to_be_bytes()[2..]);
+        data[TIMESTAMP_SIZE..].copy_from_slice(residual);
+        Ok(Uuid::from_bytes(data))
+    }
+}
+
+impl Iterator for Decoder<'_> {
+    type Item = Result<Uuid, DeltaError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.input.is_empty() {
+            return None;
+        }
+
+        let result = self.decode_next();
+        if result.is_err() {
+            self.input = &[];
+        }
+        Some(result)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+///Appends UUIDs decoded from delta encoded `input` to `out`.
+///
+///Only available when `alloc` feature is enabled.
+pub fn decode(input: &[u8], out: &mut alloc::vec::Vec<Uuid>) -> Result<(), DeltaError> {
+    for uuid in Decoder::new(input) {
+        out.push(uuid?);
+    }
+    Ok(())
+}
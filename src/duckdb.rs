@@ -0,0 +1,52 @@
+use duckdb::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+use crate::{UUID_SIZE, Uuid};
+
+impl ToSql for Uuid {
+    #[inline]
+    fn to_sql(&self) -> duckdb::Result<ToSqlOutput<'_>> {
+        //DuckDB has no dedicated UUID parameter, but casts 16 bytes BLOB in RFC order to its native UUID losslessly.
+        Ok(ToSqlOutput::Borrowed(ValueRef::Blob(self.as_bytes())))
+    }
+}
+
+impl FromSql for Uuid {
+    #[inline]
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            //Native UUID is exported as 16 bytes with lossless conversion enabled, and as text otherwise.
+            ValueRef::Blob(bytes) if bytes.len() == UUID_SIZE => Uuid::from_slice(bytes).ok_or(FromSqlError::InvalidType),
+            ValueRef::Text(text) => Uuid::parse_ascii_bytes(text).map_err(|_| FromSqlError::InvalidType),
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Uuid;
+
+    use duckdb::types::{FromSql, ToSql, ToSqlOutput, ValueRef};
+
+    #[test]
+    fn should_convert_to_blob() {
+        let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+        match uuid.to_sql().unwrap() {
+            ToSqlOutput::Borrowed(ValueRef::Blob(bytes)) => assert_eq!(bytes, uuid.as_bytes()),
+            _ => panic!("Unexpected value"),
+        }
+    }
+
+    #[test]
+    fn should_convert_from_value() {
+        let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+        let value = ValueRef::Text(b"60ecb7b6-ba34-5aad-a9ef-9020b1ea210a");
+        assert_eq!(Uuid::column_result(value).unwrap(), uuid);
+        let value = ValueRef::Blob(uuid.as_bytes());
+        assert_eq!(Uuid::column_result(value).unwrap(), uuid);
+
+        Uuid::column_result(ValueRef::Text(b"60ecb7b6")).unwrap_err();
+        Uuid::column_result(ValueRef::Blob(&[1, 2, 3])).unwrap_err();
+        Uuid::column_result(ValueRef::Null).unwrap_err();
+    }
+}
@@ -0,0 +1,108 @@
+//!Formatting adapters for `Uuid`, selecting textual representation used by `Display`.
+//!
+//!Adapters are transparent wrappers over `Uuid`, writing text into stack buffer when formatted.
+
+use core::fmt;
+
+use crate::Uuid;
+
+macro_rules! define_adapter {
+    ($(#[$meta:meta])* $name:ident => $method:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Eq, Hash, PartialEq, PartialOrd, Ord)]
+        #[repr(transparent)]
+        pub struct $name(Uuid);
+
+        impl $name {
+            #[inline(always)]
+            ///Creates new adapter over `uuid`.
+            pub const fn from_uuid(uuid: Uuid) -> Self {
+                Self(uuid)
+            }
+
+            #[inline(always)]
+            ///Returns underlying `Uuid`.
+            pub const fn into_uuid(self) -> Uuid {
+                self.0
+            }
+        }
+
+        impl From<Uuid> for $name {
+            #[inline(always)]
+            fn from(uuid: Uuid) -> Self {
+                Self(uuid)
+            }
+        }
+
+        impl From<$name> for Uuid {
+            #[inline(always)]
+            fn from(adapter: $name) -> Self {
+                adapter.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            #[inline]
+            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt.pad(self.0.$method().as_str())
+            }
+        }
+
+        impl fmt::Debug for $name {
+            #[inline(always)]
+            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, fmt)
+            }
+        }
+    };
+}
+
+define_adapter!(
+    ///Formats UUID as `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`.
+    Hyphenated => to_str
+);
+
+define_adapter!(
+    ///Formats UUID as `xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx`.
+    Simple => to_simple_str
+);
+
+define_adapter!(
+    ///Formats UUID as `{xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx}`.
+    Braced => to_braced_str
+);
+
+define_adapter!(
+    ///Formats UUID as `urn:uuid:xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`.
+    Urn => to_urn_str
+);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+///Format specifier of .NET `Guid.ToString`.
+pub enum DotNet {
+    ///32 digits, `00000000000000000000000000000000`.
+    N,
+    ///32 digits separated by hyphens, `00000000-0000-0000-0000-000000000000`.
+    D,
+    ///Hyphenated digits enclosed in braces, `{00000000-0000-0000-0000-000000000000}`.
+    B,
+    ///Hyphenated digits enclosed in parentheses, `(00000000-0000-0000-0000-000000000000)`.
+    P,
+    ///Hexadecimal values of fields, `{0x00000000,0x0000,0x0000,{0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00}}`.
+    X,
+}
+
+impl DotNet {
+    #[inline]
+    ///Creates format from its specifier character, case insensitive.
+    pub const fn from_specifier(specifier: char) -> Option<Self> {
+        match specifier {
+            'n' | 'N' => Some(DotNet::N),
+            'd' | 'D' => Some(DotNet::D),
+            'b' | 'B' => Some(DotNet::B),
+            'p' | 'P' => Some(DotNet::P),
+            'x' | 'X' => Some(DotNet::X),
+            _ => None,
+        }
+    }
+}
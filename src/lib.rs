@@ -3,30 +3,85 @@
 //!## Features:
 //!
 //!- `md5`   - Enables v3;
-//!- `orng`  - Enables v4 using OS random, allowing unique UUIDs;
-//!- `prng`  - Enables v4 using pseudo random, allowing unique, but predictable UUIDs;
+//!- `orng`  - Enables v4 and v7 using OS random, allowing unique UUIDs;
+//!- `prng`  - Enables v4 and v7 using pseudo random, allowing unique, but predictable UUIDs;
+//!- `rand`  - Enables v4 using user supplied `rand_core::RngCore`;
 //!- `sha1`  - Enables v5;
+//!- `sha256` - Enables name based v8 using SHA-256;
 //!- `serde` - Enables `serde` support;
-//!- `std`   - Enables usages of `std` facilities like getting current time.
+//!- `alloc` - Enables usages of `alloc` facilities like owned strings;
+//!- `std`   - Enables usages of `std` facilities like getting current time, implies `alloc`;
+//!- `async` - Enables async access to `UuidPool`, implies `std`;
+//!- `valuable` - Enables `valuable` support, allowing to record UUID in `tracing` as structured value;
+//!- `slog`  - Enables `slog::Value` implementation;
+//!- `log`   - Enables `log::kv::ToValue` implementation;
+//!- `mysql_common` - Enables conversion to and from `mysql_common::Value`, used by `mysql` and `mysql_async` drivers;
+//!- `tiberius` - Enables `tiberius` conversions for SQL Server `uniqueidentifier`;
+//!- `duckdb` - Enables `duckdb` conversions for `UUID` type;
+//!- `clickhouse` - Enables `clickhouse` module to serialize `UUID` in ClickHouse format, implies `serde`;
+//!- `collections` - Enables `collections` module with containers specialized for `UUID`, implies `alloc`.
 
 #![no_std]
 #![warn(missing_docs)]
 #![cfg_attr(feature = "cargo-clippy", allow(clippy::style))]
 
+#[cfg(any(feature = "alloc", feature = "mysql_common", feature = "duckdb"))]
+extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
 use core::{fmt, time, mem};
+use core::convert::TryFrom;
 
+mod macros;
 #[cfg(feature = "serde")]
 mod serde;
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use ::serde as __serde;
+#[cfg(feature = "valuable")]
+mod valuable;
+#[cfg(feature = "slog")]
+mod slog;
+#[cfg(feature = "log")]
+mod log;
+#[cfg(feature = "mysql_common")]
+mod mysql;
+#[cfg(feature = "tiberius")]
+mod tiberius;
+#[cfg(feature = "duckdb")]
+mod duckdb;
+#[cfg(feature = "clickhouse")]
+pub mod clickhouse;
+#[cfg(feature = "collections")]
+pub mod collections;
+pub mod bloom;
+pub mod comb;
+pub mod format;
+pub mod delta;
+pub mod migrate;
+mod node;
+#[cfg(any(feature = "md5", feature = "sha1", feature = "sha256"))]
+mod name;
+#[cfg(any(feature = "md5", feature = "sha1", feature = "sha256"))]
+pub use name::NameHasher;
+#[cfg(feature = "std")]
+mod pool;
+#[cfg(feature = "std")]
+pub use pool::UuidPool;
+#[cfg(target_has_atomic = "64")]
+pub use node::{set_node_id, node_id};
+pub use node::{MacAddress, node_id_from_name, node_id_from_ipv6};
+#[cfg(feature = "std")]
+pub use node::hostname_node_id;
 
 type StrBuf = str_buf::StrBuf<36>;
+#[derive(Clone, Copy)]
 #[repr(transparent)]
-///Textual representation of UUID
-pub struct TextRepr(str_buf::StrBuf<36>);
+///Textual representation of UUID, stored in fixed buffer of size `N`
+pub struct TextRepr<const N: usize = 36>(str_buf::StrBuf<N>);
 
-impl TextRepr {
+impl<const N: usize> TextRepr<N> {
     #[inline(always)]
     ///Returns raw bytes
     pub const fn as_bytes(&self) -> &[u8] {
@@ -38,9 +93,24 @@ impl TextRepr {
     pub const fn as_str(&self) -> &str {
         self.0.as_str()
     }
+
+    #[inline]
+    ///Returns underlying buffer as array.
+    ///
+    ///If text is shorter than `N` (e.g. OID), remaining bytes are zero.
+    pub const fn into_array(self) -> [u8; N] {
+        let bytes = self.as_bytes();
+        let mut result = [0; N];
+        let mut idx = 0;
+        while idx < bytes.len() {
+            result[idx] = bytes[idx];
+            idx += 1;
+        }
+        result
+    }
 }
 
-impl core::ops::Deref for TextRepr {
+impl<const N: usize> core::ops::Deref for TextRepr<N> {
     type Target = str;
 
     #[inline(always)]
@@ -49,35 +119,79 @@ impl core::ops::Deref for TextRepr {
     }
 }
 
-impl PartialEq<TextRepr> for &str {
+impl<const N: usize> PartialEq for TextRepr<N> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for TextRepr<N> {}
+
+impl<const N: usize> PartialOrd for TextRepr<N> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for TextRepr<N> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const N: usize> core::hash::Hash for TextRepr<N> {
+    #[inline(always)]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<const N: usize> AsRef<str> for TextRepr<N> {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> PartialEq<TextRepr<N>> for &str {
     #[inline(always)]
-    fn eq(&self, other: &TextRepr) -> bool {
+    fn eq(&self, other: &TextRepr<N>) -> bool {
         *self == other.as_str()
     }
 }
 
-impl PartialEq<TextRepr> for str {
+impl<const N: usize> PartialEq<TextRepr<N>> for str {
     #[inline(always)]
-    fn eq(&self, other: &TextRepr) -> bool {
+    fn eq(&self, other: &TextRepr<N>) -> bool {
         self == other.as_str()
     }
 }
 
-impl PartialEq<str> for TextRepr {
+impl<const N: usize> PartialEq<str> for TextRepr<N> {
     #[inline(always)]
     fn eq(&self, other: &str) -> bool {
         self.as_str() == other
     }
 }
 
-impl PartialEq<&str> for TextRepr {
+impl<const N: usize> PartialEq<&str> for TextRepr<N> {
     #[inline(always)]
     fn eq(&self, other: &&str) -> bool {
         self.as_str() == *other
     }
 }
 
-impl fmt::Debug for TextRepr {
+impl<const N: usize> fmt::Display for TextRepr<N> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(self.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Debug for TextRepr<N> {
     #[inline(always)]
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         fmt::Debug::fmt(self.as_str(), fmt)
@@ -85,6 +199,7 @@ impl fmt::Debug for TextRepr {
 }
 
 const SEP: u8 = b'-';
+const URN_PREFIX: &[u8] = b"urn:uuid:";
 
 #[inline(always)]
 const fn byte_to_hex(byt: u8, idx: usize) -> u8 {
@@ -95,23 +210,381 @@ const fn byte_to_hex(byt: u8, idx: usize) -> u8 {
     HEX_DIGITS[((byt as usize) >> (BASE * idx)) & BASE_DIGIT]
 }
 
+const INVALID_HEX: u8 = 0xff;
+//Maps ascii character to its hex digit value, or `INVALID_HEX`
+const HEX_LUT: [u8; 256] = {
+    let mut lut = [INVALID_HEX; 256];
+    let mut idx = 0;
+    while idx < 10 {
+        lut[b'0' as usize + idx] = idx as u8;
+        idx += 1;
+    }
+    idx = 0;
+    while idx < 6 {
+        lut[b'a' as usize + idx] = 10 + idx as u8;
+        lut[b'A' as usize + idx] = 10 + idx as u8;
+        idx += 1;
+    }
+    lut
+};
+
 #[inline]
 const fn hex_to_byte(hex: &[u8], cursor: usize) -> Result<u8, ParseError> {
-    let left = match hex[cursor] {
-        chr @ b'0'..=b'9' => chr - b'0',
-        chr @ b'a'..=b'f' => chr - b'a' + 10,
-        chr @ b'A'..=b'F' => chr - b'A' + 10,
-        chr => return Err(ParseError::InvalidByte(chr, cursor)),
+    let left = HEX_LUT[hex[cursor] as usize];
+    let right = HEX_LUT[hex[cursor + 1] as usize];
+
+    //Invalid digit has upper bits set, so single check is enough for common case
+    if (left | right) & 0xf0 != 0 {
+        if left == INVALID_HEX {
+            return Err(ParseError::InvalidByte(hex[cursor], cursor));
+        }
+        return Err(ParseError::InvalidByte(hex[cursor + 1], cursor + 1));
+    }
+
+    Ok((left << 4) | right)
+}
+
+//Checks that `input[start..end]` consists of hex digits only
+const fn is_hex_range(input: &[u8], start: usize, end: usize) -> bool {
+    let mut cursor = start;
+    while cursor < end {
+        if HEX_LUT[input[cursor] as usize] == INVALID_HEX {
+            return false;
+        }
+        cursor += 1;
+    }
+    true
+}
+
+#[inline(always)]
+const fn to_upper_hex(hex: u8) -> u8 {
+    match hex {
+        b'a'..=b'f' => hex - b'a' + b'A',
+        hex => hex,
+    }
+}
+
+//Writes hex of `data` into text representation, optionally separating groups with `-`
+const fn encode_hex<const N: usize>(data: &[u8; UUID_SIZE], prefix: &[u8], separated: bool, upper: bool, suffix: &[u8]) -> TextRepr<N> {
+    let mut storage = [mem::MaybeUninit::uninit(); N];
+    let mut cursor = 0;
+    while cursor < prefix.len() {
+        storage[cursor] = mem::MaybeUninit::new(prefix[cursor]);
+        cursor += 1;
+    }
+
+    let mut idx = 0;
+    while idx < UUID_SIZE {
+        if separated && (idx == 4 || idx == 6 || idx == 8 || idx == 10) {
+            storage[cursor] = mem::MaybeUninit::new(SEP);
+            cursor += 1;
+        }
+        let (high, low) = match upper {
+            true => (to_upper_hex(byte_to_hex(data[idx], 1)), to_upper_hex(byte_to_hex(data[idx], 0))),
+            false => (byte_to_hex(data[idx], 1), byte_to_hex(data[idx], 0)),
+        };
+        storage[cursor] = mem::MaybeUninit::new(high);
+        storage[cursor + 1] = mem::MaybeUninit::new(low);
+        cursor += 2;
+        idx += 1;
+    }
+
+    idx = 0;
+    while idx < suffix.len() {
+        storage[cursor] = mem::MaybeUninit::new(suffix[idx]);
+        cursor += 1;
+        idx += 1;
+    }
+
+    unsafe {
+        TextRepr(str_buf::StrBuf::from_storage(storage, cursor as u8))
+    }
+}
+
+//Writes hex of `data` into beginning of `out`, optionally separating groups with `-`
+fn write_hex<'a>(data: &[u8; UUID_SIZE], out: &'a mut [u8], separated: bool) -> Result<&'a str, EncodeError> {
+    let required = match separated {
+        true => StrBuf::capacity(),
+        false => UUID_SIZE * 2,
+    };
+    if out.len() < required {
+        return Err(EncodeError {
+            required,
+        });
+    }
+
+    let mut cursor = 0;
+    for (idx, byte) in data.iter().enumerate() {
+        if separated && (idx == 4 || idx == 6 || idx == 8 || idx == 10) {
+            out[cursor] = SEP;
+            cursor += 1;
+        }
+        out[cursor] = byte_to_hex(*byte, 1);
+        out[cursor + 1] = byte_to_hex(*byte, 0);
+        cursor += 2;
+    }
+
+    //Only ascii characters are written
+    Ok(unsafe {
+        core::str::from_utf8_unchecked(&out[..cursor])
+    })
+}
+
+const DOTNET_MAX_LEN: usize = 68;
+
+//Writes .NET `X` format, i.e. `{0x00000000,0x0000,0x0000,{0x00,0x00,0x00,0x00,0x00,0x00,0x00,0x00}}`
+const fn encode_dotnet_x(data: &[u8; UUID_SIZE]) -> TextRepr<DOTNET_MAX_LEN> {
+    let mut storage = [mem::MaybeUninit::uninit(); DOTNET_MAX_LEN];
+    storage[0] = mem::MaybeUninit::new(b'{');
+    let mut cursor = 1;
+    let mut idx = 0;
+    while idx < UUID_SIZE {
+        //Fields are `0x` prefixed, except continuation of first 3 fields
+        if idx == 0 || (idx >= 4 && idx != 5 && idx != 7) {
+            if idx > 0 {
+                storage[cursor] = mem::MaybeUninit::new(b',');
+                cursor += 1;
+            }
+            if idx == 8 {
+                storage[cursor] = mem::MaybeUninit::new(b'{');
+                cursor += 1;
+            }
+            storage[cursor] = mem::MaybeUninit::new(b'0');
+            storage[cursor + 1] = mem::MaybeUninit::new(b'x');
+            cursor += 2;
+        }
+        storage[cursor] = mem::MaybeUninit::new(byte_to_hex(data[idx], 1));
+        storage[cursor + 1] = mem::MaybeUninit::new(byte_to_hex(data[idx], 0));
+        cursor += 2;
+        idx += 1;
+    }
+    storage[cursor] = mem::MaybeUninit::new(b'}');
+    storage[cursor + 1] = mem::MaybeUninit::new(b'}');
+
+    unsafe {
+        TextRepr(str_buf::StrBuf::from_storage(storage, DOTNET_MAX_LEN as u8))
+    }
+}
+
+#[inline]
+const fn skip_whitespace(input: &[u8], mut idx: usize) -> usize {
+    while idx < input.len() && input[idx].is_ascii_whitespace() {
+        idx += 1;
+    }
+    idx
+}
+
+//Expects `chr`, possibly preceded by whitespace, returning position after it
+const fn expect_byte(input: &[u8], idx: usize, chr: u8) -> Result<usize, ParseError> {
+    let idx = skip_whitespace(input, idx);
+    if idx >= input.len() {
+        Err(ParseError::InvalidLength(input.len()))
+    } else if input[idx] != chr {
+        Err(ParseError::InvalidByte(input[idx], idx))
+    } else {
+        Ok(idx + 1)
+    }
+}
+
+//Parses `0x` prefixed hex value of up to `max_digits`, possibly preceded by whitespace
+const fn expect_hex_value(input: &[u8], idx: usize, max_digits: usize) -> Result<(u32, usize), ParseError> {
+    let mut idx = match expect_byte(input, idx, b'0') {
+        Ok(idx) => idx,
+        Err(error) => return Err(error),
     };
+    if idx >= input.len() {
+        return Err(ParseError::InvalidLength(input.len()));
+    } else if input[idx] != b'x' && input[idx] != b'X' {
+        return Err(ParseError::InvalidByte(input[idx], idx));
+    }
+    idx += 1;
+
+    let start = idx;
+    let mut value = 0u32;
+    while idx < input.len() {
+        let digit = match input[idx] {
+            chr @ b'0'..=b'9' => chr - b'0',
+            chr @ b'a'..=b'f' => chr - b'a' + 10,
+            chr @ b'A'..=b'F' => chr - b'A' + 10,
+            _ => break,
+        };
+        if idx - start == max_digits {
+            return Err(ParseError::InvalidByte(input[idx], idx));
+        }
+        value = (value << 4) | digit as u32;
+        idx += 1;
+    }
+
+    if idx == start {
+        if idx >= input.len() {
+            return Err(ParseError::InvalidLength(input.len()));
+        }
+        return Err(ParseError::InvalidByte(input[idx], idx));
+    }
+    Ok((value, idx))
+}
+
+const LITERAL_LEN: usize = 2 + UUID_SIZE * 4 + (UUID_SIZE - 1) * 2;
+
+//Writes bytes of `data` as comma separated hex literals, enclosed by `open` and `close`
+const fn encode_literal(data: &[u8; UUID_SIZE], open: u8, close: u8) -> TextRepr<LITERAL_LEN> {
+    let mut storage = [mem::MaybeUninit::uninit(); LITERAL_LEN];
+    storage[0] = mem::MaybeUninit::new(open);
+    let mut cursor = 1;
+    let mut idx = 0;
+    while idx < UUID_SIZE {
+        if idx > 0 {
+            storage[cursor] = mem::MaybeUninit::new(b',');
+            storage[cursor + 1] = mem::MaybeUninit::new(b' ');
+            cursor += 2;
+        }
+        storage[cursor] = mem::MaybeUninit::new(b'0');
+        storage[cursor + 1] = mem::MaybeUninit::new(b'x');
+        storage[cursor + 2] = mem::MaybeUninit::new(byte_to_hex(data[idx], 1));
+        storage[cursor + 3] = mem::MaybeUninit::new(byte_to_hex(data[idx], 0));
+        cursor += 4;
+        idx += 1;
+    }
+    storage[cursor] = mem::MaybeUninit::new(close);
+
+    unsafe {
+        TextRepr(str_buf::StrBuf::from_storage(storage, LITERAL_LEN as u8))
+    }
+}
+
+const BASE64_LEN: usize = 22;
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64_URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+//Encodes 128 bits as 22 base64 characters, without padding
+const fn encode_base64(data: &[u8; UUID_SIZE], alphabet: &[u8; 64]) -> TextRepr<BASE64_LEN> {
+    let value = u128::from_be_bytes(*data);
+    let mut storage = [mem::MaybeUninit::uninit(); BASE64_LEN];
+    let mut idx = 0;
+    while idx < BASE64_LEN - 1 {
+        storage[idx] = mem::MaybeUninit::new(alphabet[((value >> (122 - 6 * idx)) & 0x3f) as usize]);
+        idx += 1;
+    }
+    //Last character holds remaining 2 bits
+    storage[idx] = mem::MaybeUninit::new(alphabet[((value & 0x3) << 4) as usize]);
 
-    let right = match hex[cursor + 1] {
-        chr @ b'0'..=b'9' => chr - b'0',
-        chr @ b'a'..=b'f' => chr - b'a' + 10,
-        chr @ b'A'..=b'F' => chr - b'A' + 10,
-        chr => return Err(ParseError::InvalidByte(chr, cursor + 1)),
+    unsafe {
+        TextRepr(str_buf::StrBuf::from_storage(storage, BASE64_LEN as u8))
+    }
+}
+
+//Decodes 22 base64 characters, optionally followed by `==` padding
+//
+//Digits 62 and 63 are accepted from both `alphabet` and `other`
+const fn decode_base64(input: &[u8], alphabet: &[u8; 64], other: &[u8; 64]) -> Result<Uuid, DecodeError> {
+    let len = match input.len() {
+        BASE64_LEN => BASE64_LEN,
+        24 if input[22] == b'=' && input[23] == b'=' => BASE64_LEN,
+        len => return Err(DecodeError::InvalidLength(len)),
     };
 
-    Ok(left * 16 + right)
+    let mut value = 0u128;
+    let mut idx = 0;
+    while idx < len {
+        let chr = input[idx];
+        let digit = match chr {
+            b'A'..=b'Z' => chr - b'A',
+            b'a'..=b'z' => chr - b'a' + 26,
+            b'0'..=b'9' => chr - b'0' + 52,
+            chr if chr == alphabet[62] || chr == other[62] => 62,
+            chr if chr == alphabet[63] || chr == other[63] => 63,
+            chr => return Err(DecodeError::InvalidDigit(chr, idx)),
+        };
+
+        if idx == len - 1 {
+            //Only 2 upper bits of last character are used
+            if digit & 0xf != 0 {
+                return Err(DecodeError::InvalidDigit(chr, idx));
+            }
+            value = (value << 2) | (digit >> 4) as u128;
+        } else {
+            value = (value << 6) | digit as u128;
+        }
+        idx += 1;
+    }
+
+    Ok(Uuid::from_bytes(value.to_be_bytes()))
+}
+
+const BASE32_LEN: usize = 26;
+const BASE32: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const BASE58_LEN: usize = 22;
+const BASE58: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+///Continues FNV-1a `hash` with provided `bytes`.
+const fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut idx = 0;
+    while idx < bytes.len() {
+        hash ^= bytes[idx] as u64;
+        hash = hash.wrapping_mul(PRIME);
+        idx += 1;
+    }
+
+    hash
+}
+
+#[cfg(feature = "std")]
+struct Fnv(u64);
+
+#[cfg(feature = "std")]
+impl core::hash::Hasher for Fnv {
+    #[inline(always)]
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    #[inline(always)]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0 = fnv1a(self.0, bytes);
+    }
+}
+
+const GROUP_LENS: [usize; 5] = [8, 4, 4, 4, 12];
+
+///Validates layout of `-` separated input, which is expected to have exactly 36 characters.
+const fn validate_groups(input: &[u8]) -> Result<(), ParseError> {
+    let mut group = 0;
+    let mut start = 0;
+
+    while group < GROUP_LENS.len() {
+        let end = start + GROUP_LENS[group];
+        let mut cursor = start;
+
+        while cursor < end {
+            if input[cursor] == SEP {
+                return Err(ParseError::InvalidGroupLen(group as u8 + 1, cursor - start));
+            }
+            cursor += 1;
+        }
+
+        if end == input.len() {
+            break;
+        } else if input[end] != SEP {
+            //Separator is replaced by something entirely different
+            if !input[end].is_ascii_hexdigit() {
+                return Err(ParseError::InvalidGroup(group as u8 + 1));
+            }
+
+            while cursor < input.len() && input[cursor] != SEP {
+                cursor += 1;
+            }
+            return Err(ParseError::InvalidGroupLen(group as u8 + 1, cursor - start));
+        }
+
+        start = end + 1;
+        group += 1;
+    }
+
+    Ok(())
 }
 
 macro_rules! hex_to_byte_try {
@@ -123,6 +596,16 @@ macro_rules! hex_to_byte_try {
     }
 }
 
+//`?` for use within const fn
+macro_rules! const_try {
+    ($expr:expr) => {
+        match $expr {
+            Ok(result) => result,
+            Err(error) => return Err(error),
+        }
+    }
+}
+
 ///When this namespace is specified, the name string is a fully-qualified domain name
 pub const NAMESPACE_DNS: Uuid = Uuid::from_bytes([
      0x6b, 0xa7, 0xb8, 0x10, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8
@@ -143,6 +626,49 @@ pub const NAMESPACE_X500: Uuid = Uuid::from_bytes([
     0x6b, 0xa7, 0xb8, 0x14, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8
 ]);
 
+///Local domain of DCE Security (`v2`) UUID.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Domain {
+    ///POSIX UID domain.
+    Person = 0,
+    ///POSIX GID domain.
+    Group,
+    ///Organization domain.
+    Org,
+}
+
+impl Domain {
+    #[inline(always)]
+    ///Returns numeric value of domain, as it is stored within UUID.
+    pub const fn as_u8(self) -> u8 {
+        self as u8
+    }
+
+    #[inline]
+    ///Creates domain from its numeric value, returning `None` if it is unknown.
+    pub const fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Domain::Person),
+            1 => Some(Domain::Group),
+            2 => Some(Domain::Org),
+            _ => None,
+        }
+    }
+}
+
+///Variant of UUID, denoting layout of the rest of UUID.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Variant {
+    ///Reserved for NCS backward compatibility, bit pattern `0xx`.
+    Ncs,
+    ///Variant specified by RFC4122 and RFC9562, bit pattern `10x`.
+    Rfc4122,
+    ///Reserved for Microsoft backward compatibility, bit pattern `110`.
+    Microsoft,
+    ///Reserved for future definition, bit pattern `111`.
+    Future,
+}
+
 /// The version of the UUID, denoting the generating algorithm.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Version {
@@ -158,6 +684,100 @@ pub enum Version {
     Random,
     /// Version 5: SHA-1 hash.
     Sha1,
+    /// Version 6: Reordered MAC address, as per RFC9562.
+    SortMac,
+    /// Version 7: Unix timestamp with random, as per RFC9562.
+    SortRand,
+    /// Version 8: Custom layout, as per RFC9562.
+    Custom,
+    /// Version that is not defined by RFC9562.
+    ///
+    /// Only returned by `Uuid::version`, and has no numeric value.
+    Unknown,
+}
+
+impl Version {
+    #[inline(always)]
+    ///Returns numeric value of version, as it is stored within UUID.
+    ///
+    ///`Unknown` has no numeric value, hence `None` is returned for it.
+    pub const fn as_u8(self) -> Option<u8> {
+        match self {
+            Version::Unknown => None,
+            version => Some(version as u8),
+        }
+    }
+}
+
+impl TryFrom<u8> for Version {
+    type Error = InvalidVersion;
+
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Version::Nil),
+            1 => Ok(Version::Mac),
+            2 => Ok(Version::Dce),
+            3 => Ok(Version::Md5),
+            4 => Ok(Version::Random),
+            5 => Ok(Version::Sha1),
+            6 => Ok(Version::SortMac),
+            7 => Ok(Version::SortRand),
+            8 => Ok(Version::Custom),
+            _ => Err(InvalidVersion),
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Version::Nil => fmt.write_str("nil"),
+            Version::Unknown => fmt.write_str("unknown"),
+            version => fmt.write_fmt(format_args!("v{}", *version as u8)),
+        }
+    }
+}
+
+impl core::str::FromStr for Version {
+    type Err = InvalidVersion;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if input.eq_ignore_ascii_case("nil") {
+            return Ok(Version::Nil);
+        }
+
+        match input.as_bytes() {
+            [b'v' | b'V', digit @ b'1'..=b'9'] => Version::try_from(digit - b'0'),
+            _ => Err(InvalidVersion),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+///Error happening when converting unknown version.
+pub struct InvalidVersion;
+
+impl fmt::Display for InvalidVersion {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("Unknown UUID version")
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+///Error happening when output buffer is too small to hold textual representation.
+pub struct EncodeError {
+    ///Number of bytes required to hold textual representation.
+    pub required: usize,
+}
+
+impl fmt::Display for EncodeError {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_fmt(format_args!("Buffer is too small, required {} bytes", self.required))
+    }
 }
 
 #[derive(Clone, Debug, Copy)]
@@ -205,6 +825,24 @@ impl Timestamp {
         Self::from_unix(now)
     }
 
+    #[cfg(all(feature = "std", target_has_atomic = "64"))]
+    //Current time, advanced past last returned value so that timestamps are unique within process.
+    fn now_unique() -> Self {
+        use core::sync::atomic::{AtomicU64, Ordering};
+
+        static LAST_TICKS: AtomicU64 = AtomicU64::new(0);
+
+        let now = Self::now().ticks;
+        let mut last = LAST_TICKS.load(Ordering::Relaxed);
+        loop {
+            let ticks = if now > last { now } else { last + 1 };
+            match LAST_TICKS.compare_exchange_weak(last, ticks, Ordering::Relaxed, Ordering::Relaxed) {
+                Ok(_) => break Self::from_parts(ticks, 0),
+                Err(actual) => last = actual,
+            }
+        }
+    }
+
     #[inline]
     ///Sets counter to further avoid chance of collision between timestamps.
     ///
@@ -222,48 +860,305 @@ impl Timestamp {
     }
 }
 
-const UUID_SIZE: usize = 16;
-
-#[derive(Clone, Copy, Eq, Hash, PartialEq, PartialOrd, Ord)]
-#[repr(transparent)]
-///Universally unique identifier, consisting of 128-bits, as according to RFC4122
-pub struct Uuid {
-    data: [u8; UUID_SIZE]
+#[cfg(feature = "prng")]
+///Stream of pseudo random numbers, used to generate predictable `v4` UUIDs.
+///
+///Each stream is identified by its key, and streams with different keys produce independent sequences.
+///
+///Only available when `prng` feature is enabled.
+pub struct PrngStream {
+    rand: squares_rnd::Rand,
+    entropy: core::sync::atomic::AtomicU64,
 }
 
-impl Uuid {
-    #[inline]
-    ///Creates zero UUID
-    pub const fn nil() -> Self {
-        Self::from_bytes([0; UUID_SIZE])
+#[cfg(feature = "prng")]
+impl PrngStream {
+    #[inline(always)]
+    ///Creates new stream with provided `key`.
+    ///
+    ///Key should be random-like value (i.e. with roughly half of bits set) to produce good quality output.
+    pub const fn new(key: u64) -> Self {
+        Self {
+            rand: squares_rnd::Rand::new(key),
+            entropy: core::sync::atomic::AtomicU64::new(0),
+        }
     }
 
-    #[inline]
-    ///Creates new Uuid from raw bytes.
-    pub const fn from_bytes(data: [u8; UUID_SIZE]) -> Self {
-        Self { data }
+    ///Folds user supplied `entropy` (e.g. device serial or boot nonce) into the stream.
+    ///
+    ///Output of the stream remains unique, but its sequence becomes specific to supplied entropy.
+    ///Intended to be called once on startup, before generating any UUID.
+    pub fn mix_entropy(&self, entropy: &[u8]) {
+        use core::sync::atomic::Ordering;
+
+        let mut current = self.entropy.load(Ordering::Acquire);
+        loop {
+            let new = fnv1a(fnv1a(FNV_OFFSET_BASIS, &current.to_ne_bytes()), entropy);
+            match self.entropy.compare_exchange_weak(current, new, Ordering::AcqRel, Ordering::Acquire) {
+                Ok(_) => break,
+                Err(actual) => current = actual,
+            }
+        }
     }
 
     #[inline]
-    ///Creates new Uuid from byte slice, if its size is 16, otherwise `None`
-    pub const fn from_slice(data: &[u8]) -> Option<Uuid> {
-        #[cold]
-        const fn fail() -> Option<Uuid> {
-            None
-        }
+    fn next_u128(&self) -> u128 {
+        let entropy = self.entropy.load(core::sync::atomic::Ordering::Acquire);
+        let right = u128::from(self.rand.next_u64() ^ entropy);
+        let left = u128::from(self.rand.next_u64() ^ entropy.rotate_left(32));
+        (left << 64) | right
+    }
+}
 
-        if data.len() != UUID_SIZE {
-            return fail();
-        }
+#[cfg(feature = "prng")]
+static PRNG: PrngStream = PrngStream::new(1);
 
-        Some(Self::from_bytes([
-            data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7],
-            data[8], data[9], data[10], data[11], data[12], data[13], data[14], data[15],
-        ]))
+#[cfg(any(feature = "osrng", feature = "prng"))]
+#[inline]
+//Fetches random using OS RNG when `osrng` is enabled, otherwise PRNG.
+fn random_bytes() -> [u8; UUID_SIZE] {
+    #[cfg(feature = "osrng")]
+    {
+        let mut bytes = [0; UUID_SIZE];
+        if let Err(error) = getrandom::getrandom(&mut bytes[..]) {
+            panic!("OS RNG is not available for use: {}", error)
+        }
+        bytes
     }
+    #[cfg(not(feature = "osrng"))]
+    {
+        PRNG.next_u128().to_ne_bytes()
+    }
+}
 
-    #[inline]
-    ///Creates `UUID` from `GUID` converting integer fields to big endian while `d4` is copied as
+#[cfg(feature = "osrng")]
+///Pool of OS random, fetched in chunks to amortize cost of system calls.
+///
+///Only available when `osrng` feature is enabled.
+///
+///Note that pool keeps unused random bytes in memory, until they are used to generate `v4`.
+pub struct EntropyPool {
+    buffer: [u8; EntropyPool::SIZE],
+    cursor: usize,
+}
+
+#[cfg(feature = "osrng")]
+impl EntropyPool {
+    ///Number of bytes fetched from OS RNG at once.
+    pub const SIZE: usize = 1024;
+
+    #[inline(always)]
+    ///Creates new empty pool, which is filled on first use.
+    pub const fn new() -> Self {
+        Self {
+            buffer: [0; Self::SIZE],
+            cursor: Self::SIZE,
+        }
+    }
+
+    ///Generates UUID `v4` using pooled random, re-filling pool once it is exhausted.
+    pub fn v4(&mut self) -> Uuid {
+        if self.cursor == Self::SIZE {
+            if let Err(error) = getrandom::getrandom(&mut self.buffer) {
+                panic!("OS RNG is not available for use: {}", error)
+            }
+            self.cursor = 0;
+        }
+
+        let chunk = &mut self.buffer[self.cursor..self.cursor + UUID_SIZE];
+        let mut bytes = [0; UUID_SIZE];
+        bytes.copy_from_slice(chunk);
+        //Erase used random, so that it cannot be leaked
+        chunk.fill(0);
+        self.cursor += UUID_SIZE;
+        Uuid::v4_from(bytes)
+    }
+}
+
+#[cfg(feature = "osrng")]
+impl Default for EntropyPool {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///Generator of `v7` UUIDs, guaranteeing strictly increasing order.
+///
+///Layout is:
+///
+///- 48 bits of unix timestamp in milliseconds, provided by `clock`;
+///- 12 bits of counter in place of `rand_a`, which is reset each millisecond;
+///- 62 bits of random in place of `rand_b`.
+///
+///When counter overflows within the same millisecond, timestamp is advanced by one millisecond.
+///If clock goes backwards, last timestamp is used instead.
+///
+///Clock is supplied by user as function, returning unix timestamp in milliseconds, which allows to use it
+///without `std`.
+pub struct V7Generator<C> {
+    clock: C,
+    last_ms: u64,
+    counter: u16,
+}
+
+impl<C: FnMut() -> u64> V7Generator<C> {
+    #[inline(always)]
+    ///Creates new generator, using `clock` to fetch unix timestamp in milliseconds.
+    pub const fn new(clock: C) -> Self {
+        Self {
+            clock,
+            last_ms: 0,
+            counter: 0,
+        }
+    }
+
+    ///Generates next UUID, using `rand` to fill `rand_b`.
+    pub fn next_from_random(&mut self, rand: [u8; 8]) -> Uuid {
+        const COUNTER_MAX: u16 = 0x0FFF;
+
+        let now = (self.clock)();
+        if now > self.last_ms {
+            self.last_ms = now;
+            self.counter = 0;
+        } else if self.counter == COUNTER_MAX {
+            self.last_ms += 1;
+            self.counter = 0;
+        } else {
+            self.counter += 1;
+        }
+
+        let counter = self.counter.to_be_bytes();
+        Uuid::v7_from_parts(self.last_ms, [
+            counter[0], counter[1], rand[0], rand[1], rand[2], rand[3], rand[4], rand[5], rand[6], rand[7],
+        ])
+    }
+
+    #[cfg(any(feature = "osrng", feature = "prng"))]
+    #[inline]
+    ///Generates next UUID.
+    ///
+    ///Uses OS RNG when `osrng` is enabled, otherwise PRNG.
+    ///
+    ///Only available when either `osrng` or `prng` feature is enabled.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Uuid {
+        let rand = random_bytes();
+        self.next_from_random([rand[0], rand[1], rand[2], rand[3], rand[4], rand[5], rand[6], rand[7]])
+    }
+}
+
+#[cfg(feature = "std")]
+impl V7Generator<fn() -> u64> {
+    ///Creates new generator, using system time as clock.
+    ///
+    ///Only available when `std` feature is enabled.
+    pub fn system() -> Self {
+        fn now() -> u64 {
+            use std::time::{SystemTime, UNIX_EPOCH};
+
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).expect("System time is behind unix epoch");
+            now.as_millis() as u64
+        }
+
+        Self::new(now)
+    }
+}
+
+///Source of UUIDs, allowing to abstract over particular way of generation.
+///
+///Implemented for any `Fn() -> Uuid`, hence generator functions such as `Uuid::v4` can be used as they are.
+pub trait UuidGenerator {
+    ///Generates new UUID.
+    fn generate(&self) -> Uuid;
+}
+
+impl<F: Fn() -> Uuid> UuidGenerator for F {
+    #[inline(always)]
+    fn generate(&self) -> Uuid {
+        (self)()
+    }
+}
+
+#[cfg(feature = "prng")]
+impl UuidGenerator for PrngStream {
+    #[inline(always)]
+    fn generate(&self) -> Uuid {
+        Uuid::v4_prng_stream(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl UuidGenerator for UuidPool {
+    #[inline(always)]
+    fn generate(&self) -> Uuid {
+        self.next()
+    }
+}
+
+const UUID_SIZE: usize = 16;
+const OID_PREFIX: &[u8] = b"2.25.";
+//Prefix and up to 39 decimal digits of u128
+const OID_MAX_LEN: usize = 44;
+
+#[derive(Clone, Copy, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[repr(transparent)]
+///Universally unique identifier, consisting of 128-bits, as according to RFC4122
+pub struct Uuid {
+    data: [u8; UUID_SIZE]
+}
+
+impl Uuid {
+    #[inline]
+    ///Creates zero UUID
+    pub const fn nil() -> Self {
+        Self::from_bytes([0; UUID_SIZE])
+    }
+
+    #[inline]
+    ///Creates UUID with all bits set, as defined by RFC9562.
+    pub const fn max() -> Self {
+        Self::from_bytes([0xff; UUID_SIZE])
+    }
+
+    #[inline]
+    ///Checks if UUID is zero.
+    pub const fn is_nil(&self) -> bool {
+        u128::from_ne_bytes(self.data) == 0
+    }
+
+    #[inline]
+    ///Checks if UUID has all bits set.
+    pub const fn is_max(&self) -> bool {
+        u128::from_ne_bytes(self.data) == u128::MAX
+    }
+
+    #[inline]
+    ///Creates new Uuid from raw bytes.
+    pub const fn from_bytes(data: [u8; UUID_SIZE]) -> Self {
+        Self { data }
+    }
+
+    #[inline]
+    ///Creates new Uuid from byte slice, if its size is 16, otherwise `None`
+    pub const fn from_slice(data: &[u8]) -> Option<Uuid> {
+        #[cold]
+        const fn fail() -> Option<Uuid> {
+            None
+        }
+
+        if data.len() != UUID_SIZE {
+            return fail();
+        }
+
+        Some(Self::from_bytes([
+            data[0], data[1], data[2], data[3], data[4], data[5], data[6], data[7],
+            data[8], data[9], data[10], data[11], data[12], data[13], data[14], data[15],
+        ]))
+    }
+
+    #[inline]
+    ///Creates `UUID` from `GUID` converting integer fields to big endian while `d4` is copied as
     ///it is.
     ///
     ///Note that it is assumed that `GUID` was correctly created in little endian format (e.g.
@@ -289,6 +1184,57 @@ impl Uuid {
         ])
     }
 
+    #[inline]
+    ///Creates `UUID` from fields, stored in little endian (e.g. as `GUID` is stored in memory by Windows).
+    ///
+    ///Integer fields are converted to little endian bytes, while `d4` is copied as it is.
+    ///This is reverse of `to_fields_le`.
+    pub const fn from_fields_le(d1: u32, d2: u16, d3: u16, d4: [u8; 8]) -> Self {
+        Self::from_guid(d1.swap_bytes(), d2.swap_bytes(), d3.swap_bytes(), d4)
+    }
+
+    #[inline]
+    ///Creates `UUID` from bytes in mixed endian layout, as produced by .NET `Guid.ToByteArray`.
+    ///
+    ///First 3 fields are stored as little endian integers, while the rest is copied as it is.
+    pub const fn from_bytes_le(bytes: [u8; UUID_SIZE]) -> Self {
+        Self::from_bytes([
+            bytes[3], bytes[2], bytes[1], bytes[0], bytes[5], bytes[4], bytes[7], bytes[6],
+            bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15],
+        ])
+    }
+
+    #[inline]
+    ///Returns bytes in mixed endian layout, as expected by .NET `new Guid(byte[])`.
+    ///
+    ///This is reverse of `from_bytes_le`.
+    pub const fn to_bytes_le(&self) -> [u8; UUID_SIZE] {
+        Self::from_bytes_le(self.data).data
+    }
+
+    #[inline]
+    ///Returns fields of `UUID`, interpreting integer fields as big endian.
+    ///
+    ///This is reverse of `from_guid`.
+    pub const fn to_fields(&self) -> (u32, u16, u16, [u8; 8]) {
+        let data = &self.data;
+        (
+            u32::from_be_bytes([data[0], data[1], data[2], data[3]]),
+            u16::from_be_bytes([data[4], data[5]]),
+            u16::from_be_bytes([data[6], data[7]]),
+            [data[8], data[9], data[10], data[11], data[12], data[13], data[14], data[15]],
+        )
+    }
+
+    #[inline]
+    ///Returns fields of `UUID`, interpreting integer fields as little endian.
+    ///
+    ///This is reverse of `from_fields_le`.
+    pub const fn to_fields_le(&self) -> (u32, u16, u16, [u8; 8]) {
+        let (d1, d2, d3, d4) = self.to_fields();
+        (d1.swap_bytes(), d2.swap_bytes(), d3.swap_bytes(), d4)
+    }
+
     #[inline]
     ///Access underlying bytes as slice.
     pub const fn as_bytes(&self) -> &[u8] {
@@ -341,10 +1287,148 @@ impl Uuid {
         [self.data[10], self.data[11], self.data[12], self.data[13], self.data[14], self.data[15]]
     }
 
+    #[inline]
+    ///Returns 14-bit clock sequence of time based UUID, which corresponds to `Timestamp` counter.
+    ///
+    ///`None` is returned for versions that do not contain clock sequence.
+    pub const fn get_clock_sequence(&self) -> Option<u16> {
+        if self.is_version(Version::Mac) || self.is_version(Version::SortMac) {
+            Some(u16::from_be_bytes([self.data[8] & 0x3f, self.data[9]]))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    ///Returns local domain of DCE Security (`v2`) UUID, stored in place of `clock_seq_low`.
+    ///
+    ///`None` is returned for other versions.
+    pub const fn dce_domain(&self) -> Option<u8> {
+        if self.is_version(Version::Dce) {
+            Some(self.data[9])
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    ///Returns local identifier (e.g. UID or GID) of DCE Security (`v2`) UUID, stored in place of `time_low`.
+    ///
+    ///`None` is returned for other versions.
+    pub const fn dce_local_id(&self) -> Option<u32> {
+        if self.is_version(Version::Dce) {
+            Some(u32::from_be_bytes([self.data[0], self.data[1], self.data[2], self.data[3]]))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    ///Returns unix timestamp in milliseconds, embedded into `v7` UUID.
+    ///
+    ///`None` is returned for other versions.
+    pub const fn v7_unix_ms(&self) -> Option<u64> {
+        if self.is_version(Version::SortRand) {
+            let data = &self.data;
+            Some(u64::from_be_bytes([0, 0, data[0], data[1], data[2], data[3], data[4], data[5]]))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    ///Returns number of 100-nanoseconds intervals since 15 Oct 1582, embedded into `v1` or `v6` UUID.
+    const fn gregorian_ticks(&self) -> Option<u64> {
+        let data = &self.data;
+        if self.is_version(Version::Mac) {
+            Some(u64::from_be_bytes([
+                data[6] & 0x0f, data[7], data[4], data[5], data[0], data[1], data[2], data[3]
+            ]))
+        } else if self.is_version(Version::SortMac) {
+            Some(u64::from_be_bytes([
+                0, 0, data[0], data[1], data[2], data[3], data[4], data[5]
+            ]) << 12 | u16::from_be_bytes([data[6] & 0x0f, data[7]]) as u64)
+        } else {
+            None
+        }
+    }
+
+    #[cfg(feature = "std")]
+    ///Returns time elapsed since timestamp, embedded into `v1`, `v6` or `v7` UUID.
+    ///
+    ///`None` is returned for other versions or if timestamp is in the future.
+    ///
+    ///Only available when `std` feature is enabled.
+    pub fn age(&self) -> Option<time::Duration> {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?;
+        if let Some(unix_ms) = self.v7_unix_ms() {
+            now.checked_sub(time::Duration::from_millis(unix_ms))
+        } else if let Some(ticks) = self.gregorian_ticks() {
+            let now = Timestamp::from_unix(now).ticks;
+            let elapsed = now.checked_sub(ticks)?;
+            Some(time::Duration::new(elapsed / 10_000_000, (elapsed % 10_000_000) as u32 * 100))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    ///Returns textual representation of `node` as MAC address `aa:bb:cc:dd:ee:ff`.
+    ///
+    ///Only time based UUIDs contain node, hence `None` is returned for other versions.
+    pub const fn node_mac(&self) -> Option<TextRepr<17>> {
+        if self.is_version(Version::Mac) || self.is_version(Version::SortMac) {
+            Some(MacAddress(self.node()).to_str())
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    ///Returns version of `UUID`.
+    ///
+    ///`None` is returned if variant is not RFC4122, as version is not defined for other variants,
+    ///unless UUID is `nil`.
+    pub const fn version(&self) -> Option<Version> {
+        if self.is_nil() {
+            return Some(Version::Nil);
+        } else if !self.is_variant() {
+            return None;
+        }
+
+        Some(match self.data[6] >> 4 {
+            1 => Version::Mac,
+            2 => Version::Dce,
+            3 => Version::Md5,
+            4 => Version::Random,
+            5 => Version::Sha1,
+            6 => Version::SortMac,
+            7 => Version::SortRand,
+            8 => Version::Custom,
+            _ => Version::Unknown,
+        })
+    }
+
     #[inline]
     ///Checks if `UUID` version is equal to the provided `version`
     pub const fn is_version(&self, version: Version) -> bool {
-        (self.data[6] >> 4) == version as u8
+        match version.as_u8() {
+            Some(version) => (self.data[6] >> 4) == version,
+            None => matches!(self.version(), Some(Version::Unknown)),
+        }
+    }
+
+    #[inline]
+    ///Returns variant of `UUID`.
+    pub const fn variant(&self) -> Variant {
+        match self.data[8] >> 5 {
+            0..=3 => Variant::Ncs,
+            4 | 5 => Variant::Rfc4122,
+            6 => Variant::Microsoft,
+            _ => Variant::Future,
+        }
     }
 
     #[inline]
@@ -353,6 +1437,82 @@ impl Uuid {
         (self.data[8] & 0xc0) == 0x80
     }
 
+    #[inline]
+    ///Converts `v1` UUID into `v6`, by reordering timestamp so that it becomes sortable.
+    ///
+    ///Rest of UUID is preserved as it is, returning `None` if UUID is not `v1`.
+    pub const fn v1_to_v6(self) -> Option<Self> {
+        if !self.is_version(Version::Mac) {
+            return None;
+        }
+
+        let data = self.data;
+        let ticks = match self.gregorian_ticks() {
+            Some(ticks) => ticks,
+            None => return None,
+        };
+        let time_high = ((ticks >> 28) as u32).to_be_bytes();
+        let time_mid = ((ticks >> 12) as u16).to_be_bytes();
+        let time_low_and_version = ((ticks & 0x0FFF) as u16 | (6 << 12)).to_be_bytes();
+
+        Some(Self::from_bytes([
+            time_high[0], time_high[1], time_high[2], time_high[3],
+            time_mid[0], time_mid[1],
+            time_low_and_version[0], time_low_and_version[1],
+            data[8], data[9], data[10], data[11], data[12], data[13], data[14], data[15],
+        ]))
+    }
+
+    #[inline]
+    ///Converts `v6` UUID into `v1`, restoring original order of timestamp fields.
+    ///
+    ///Rest of UUID is preserved as it is, returning `None` if UUID is not `v6`.
+    pub const fn v6_to_v1(self) -> Option<Self> {
+        if !self.is_version(Version::SortMac) {
+            return None;
+        }
+
+        let data = self.data;
+        let ticks = match self.gregorian_ticks() {
+            Some(ticks) => ticks,
+            None => return None,
+        };
+        let time_low = (ticks as u32).to_be_bytes();
+        let time_mid = ((ticks >> 32) as u16).to_be_bytes();
+        let time_high_and_version = (((ticks >> 48) & 0x0FFF) as u16 | (1 << 12)).to_be_bytes();
+
+        Some(Self::from_bytes([
+            time_low[0], time_low[1], time_low[2], time_low[3],
+            time_mid[0], time_mid[1],
+            time_high_and_version[0], time_high_and_version[1],
+            data[8], data[9], data[10], data[11], data[12], data[13], data[14], data[15],
+        ]))
+    }
+
+    #[inline]
+    ///Returns time based UUID in `v6` layout, converting `v1` if necessary.
+    ///
+    ///`None` is returned if UUID is neither `v1` nor `v6`.
+    pub const fn to_v6(self) -> Option<Self> {
+        if self.is_version(Version::SortMac) {
+            Some(self)
+        } else {
+            self.v1_to_v6()
+        }
+    }
+
+    #[inline]
+    ///Returns time based UUID in `v1` layout, converting `v6` if necessary.
+    ///
+    ///`None` is returned if UUID is neither `v1` nor `v6`.
+    pub const fn to_v1(self) -> Option<Self> {
+        if self.is_version(Version::Mac) {
+            Some(self)
+        } else {
+            self.v6_to_v1()
+        }
+    }
+
     ///Generates UUID from time and mac address
     pub const fn v1(timestamp: Timestamp, mac: [u8; 6]) -> Self {
         let time_low = (timestamp.ticks & 0xFFFF_FFFF) as u32;
@@ -379,32 +1539,111 @@ impl Uuid {
         ])
     }
 
-    #[cfg(feature = "md5")]
-    ///Generates UUID `v3` by using `md5` hasher
+    #[inline]
+    ///Generates DCE Security UUID `v2` from time and mac address.
     ///
-    ///Only available when `md5` feature is enabled.
-    pub const fn v3(namespace: Uuid, name: &[u8]) -> Self {
-        let hash = lhash::Md5::new().const_update(&namespace.data)
-                                    .const_update(name)
-                                    .const_result();
+    ///Layout is the same as `v1`, except `time_low` is replaced with `local_id` (e.g. UID or GID),
+    ///while `clock_seq_low` is replaced with `domain`.
+    pub const fn v2(domain: Domain, local_id: u32, timestamp: Timestamp, mac: [u8; 6]) -> Self {
+        let mut result = Self::v1(timestamp, mac).set_version(Version::Dce);
+        let local_id = local_id.to_be_bytes();
+        result.data[0] = local_id[0];
+        result.data[1] = local_id[1];
+        result.data[2] = local_id[2];
+        result.data[3] = local_id[3];
+        result.data[9] = domain.as_u8();
+        result
+    }
 
-        Self::from_bytes([
-            hash[0], hash[1], hash[2], hash[3], hash[4], hash[5], hash[6], hash[7],
-            hash[8], hash[9], hash[10], hash[11], hash[12], hash[13], hash[14], hash[15],
-        ]).set_variant().set_version(Version::Md5)
+    #[inline(always)]
+    ///Generates UUID from time and mac address
+    pub const fn v1_mac(timestamp: Timestamp, mac: MacAddress) -> Self {
+        Self::v1(timestamp, mac.0)
     }
 
-    #[inline]
-    ///Constructs UUID `v4` from provided bytes, assuming they are random.
+    #[cfg(all(feature = "std", target_has_atomic = "64"))]
+    ///Generates UUID `v1` using current time and node, configured via `set_node_id`.
     ///
-    ///It is up to user to guarantee that it is random.
+    ///Refer to `v1_now_mac` for details on collision avoidance.
     ///
-    ///This function only sets corresponding bits for `v4`
-    pub const fn v4_from(random: [u8; UUID_SIZE]) -> Self {
-        Self::from_bytes(random).set_variant().set_version(Version::Random)
+    ///Returns `None` if node is not configured.
+    ///
+    ///Only available when `std` feature is enabled.
+    pub fn v1_now() -> Option<Self> {
+        node_id().map(|node| Self::v1_now_mac(MacAddress(node)))
     }
 
-    #[cfg(feature = "osrng")]
+    #[cfg(all(feature = "std", target_has_atomic = "64"))]
+    ///Generates UUID `v1` using current time and provided `mac`.
+    ///
+    ///Timestamp is tracked process-wide, and advanced by one tick whenever clock doesn't move
+    ///forward (i.e. rapid generation or clock going backwards), so that generated UUIDs are unique
+    ///within process.
+    ///
+    ///Only available when `std` feature is enabled.
+    pub fn v1_now_mac(mac: MacAddress) -> Self {
+        Self::v1_mac(Timestamp::now_unique(), mac)
+    }
+
+    #[cfg(all(feature = "std", target_has_atomic = "64"))]
+    ///Generates UUID `v6` using current time and node, configured via `set_node_id`.
+    ///
+    ///Same as `v1_now`, but in `v6` layout.
+    ///Returns `None` if node is not configured.
+    ///
+    ///Only available when `std` feature is enabled.
+    pub fn v6_now() -> Option<Self> {
+        node_id().map(|node| Self::v6_now_mac(MacAddress(node)))
+    }
+
+    #[cfg(all(feature = "std", target_has_atomic = "64"))]
+    ///Generates UUID `v6` using current time and provided `mac`.
+    ///
+    ///Same as `v1_now_mac`, but in `v6` layout.
+    ///
+    ///Only available when `std` feature is enabled.
+    pub fn v6_now_mac(mac: MacAddress) -> Self {
+        match Self::v1_now_mac(mac).v1_to_v6() {
+            Some(result) => result,
+            None => unreachable!(),
+        }
+    }
+
+    #[cfg(feature = "md5")]
+    ///Generates UUID `v3` by using `md5` hasher
+    ///
+    ///Only available when `md5` feature is enabled.
+    pub const fn v3(namespace: Uuid, name: &[u8]) -> Self {
+        let hash = lhash::Md5::new().const_update(&namespace.data)
+                                    .const_update(name)
+                                    .const_result();
+
+        Self::from_bytes([
+            hash[0], hash[1], hash[2], hash[3], hash[4], hash[5], hash[6], hash[7],
+            hash[8], hash[9], hash[10], hash[11], hash[12], hash[13], hash[14], hash[15],
+        ]).set_variant().set_version(Version::Md5)
+    }
+
+    #[cfg(feature = "md5")]
+    #[inline(always)]
+    ///Generates UUID `v3` from textual `name`.
+    ///
+    ///Only available when `md5` feature is enabled.
+    pub const fn v3_str(namespace: Uuid, name: &str) -> Self {
+        Self::v3(namespace, name.as_bytes())
+    }
+
+    #[inline]
+    ///Constructs UUID `v4` from provided bytes, assuming they are random.
+    ///
+    ///It is up to user to guarantee that it is random.
+    ///
+    ///This function only sets corresponding bits for `v4`
+    pub const fn v4_from(random: [u8; UUID_SIZE]) -> Self {
+        Self::from_bytes(random).set_variant().set_version(Version::Random)
+    }
+
+    #[cfg(feature = "osrng")]
     ///Generates UUID `v4` using OS RNG from [getrandom](https://crates.io/crates/getrandom)
     ///
     ///Only available when `osrng` feature is enabled.
@@ -417,6 +1656,61 @@ impl Uuid {
         Self::v4_from(bytes)
     }
 
+    #[cfg(feature = "rand")]
+    #[inline]
+    ///Generates UUID `v4` using provided `rng`.
+    ///
+    ///Only available when `rand` feature is enabled.
+    pub fn v4_with_rng<R: rand_core::RngCore + ?Sized>(rng: &mut R) -> Self {
+        let mut bytes = [0; UUID_SIZE];
+        rng.fill_bytes(&mut bytes);
+        Self::v4_from(bytes)
+    }
+
+    #[cfg(feature = "osrng")]
+    ///Fills `out` with UUIDs `v4`, using OS RNG to fetch random for all of them at once.
+    ///
+    ///Random bytes for the whole slice are requested via single `getrandom` call, after which only
+    ///version and variant bits are set for each UUID, avoiding per UUID syscall overhead.
+    ///
+    ///Only available when `osrng` feature is enabled.
+    pub fn v4_batch(out: &mut [Uuid]) {
+        //Uuid is transparent wrapper over bytes
+        let bytes = unsafe {
+            core::slice::from_raw_parts_mut(out.as_mut_ptr() as *mut u8, out.len() * UUID_SIZE)
+        };
+        if let Err(error) = getrandom::getrandom(bytes) {
+            panic!("OS RNG is not available for use: {}", error)
+        }
+
+        for uuid in out.iter_mut() {
+            *uuid = uuid.set_variant().set_version(Version::Random);
+        }
+    }
+
+    #[cfg(feature = "osrng")]
+    #[inline(always)]
+    ///Fills `buf` with UUIDs `v4`, using single `getrandom` call.
+    ///
+    ///Same as `v4_batch`.
+    ///
+    ///Only available when `osrng` feature is enabled.
+    pub fn v4_fill(buf: &mut [Uuid]) {
+        Self::v4_batch(buf)
+    }
+
+    #[cfg(all(feature = "std", feature = "osrng"))]
+    ///Generates UUID `v4` using OS RNG, fetched in chunks into thread local `EntropyPool`.
+    ///
+    ///Only available when `std` and `osrng` features are enabled.
+    pub fn v4_pooled() -> Self {
+        std::thread_local! {
+            static POOL: core::cell::RefCell<EntropyPool> = const { core::cell::RefCell::new(EntropyPool::new()) };
+        }
+
+        POOL.with(|pool| pool.borrow_mut().v4())
+    }
+
     #[cfg(feature = "prng")]
     ///Generates UUID `v4` using PRNG from [wyhash](https://crates.io/crates/wy)
     ///
@@ -429,10 +1723,431 @@ impl Uuid {
     ///This random is useful when you want to generate predictable but unique UUIDs
     ///Otherwise use `v4`
     pub fn v4_prng() -> Self {
-        static RANDOM: squares_rnd::Rand = squares_rnd::Rand::new(1);
-        let right = u128::from(RANDOM.next_u64());
-        let left = u128::from(RANDOM.next_u64());
-        Self::v4_from(((left << 64) |  right).to_ne_bytes())
+        Self::v4_prng_stream(&PRNG)
+    }
+
+    #[cfg(feature = "prng")]
+    #[inline(always)]
+    ///Folds user supplied `entropy` (e.g. device serial or boot nonce) into PRNG used by `v4_prng`.
+    ///
+    ///Only available when `prng` feature is enabled.
+    ///
+    ///This allows to avoid repeating the same sequence of UUIDs across different devices or restarts,
+    ///while still being predictable for the same entropy.
+    pub fn v4_prng_mix_entropy(entropy: &[u8]) {
+        PRNG.mix_entropy(entropy)
+    }
+
+    #[cfg(feature = "prng")]
+    ///Generates UUID `v4` using PRNG from provided `stream`.
+    ///
+    ///Only available when `prng` feature is enabled.
+    ///
+    ///Same as `v4_prng`, but allows to use independent streams of predictable UUIDs.
+    pub fn v4_prng_stream(stream: &PrngStream) -> Self {
+        Self::v4_from(stream.next_u128().to_ne_bytes())
+    }
+
+    #[inline]
+    ///Constructs UUID `v7` from unix timestamp in milliseconds and random bytes.
+    ///
+    ///Only lower 48 bits of `unix_ms` are used.
+    ///It is up to user to guarantee that `rand` is random.
+    ///
+    ///Output is fully determined by its inputs, which makes it suitable for tests and replaying
+    ///previously generated identifiers.
+    pub const fn v7_from_parts(unix_ms: u64, rand: [u8; 10]) -> Self {
+        let time = unix_ms.to_be_bytes();
+        Self::from_bytes([
+            time[2], time[3], time[4], time[5], time[6], time[7],
+            rand[0], rand[1], rand[2], rand[3], rand[4], rand[5], rand[6], rand[7], rand[8], rand[9],
+        ]).set_variant().set_version(Version::SortRand)
+    }
+
+    #[inline]
+    ///Constructs UUID `v7` from unix timestamp and random bytes, using sub-millisecond precision.
+    ///
+    ///Fraction of millisecond is stored in place of `rand_a` with 12 bits of precision (as per
+    ///method 3 of RFC9562), while `rand` is used to fill `rand_b`.
+    ///It is up to user to guarantee that `rand` is random.
+    pub const fn v7_precise_from_parts(time: time::Duration, rand: [u8; 8]) -> Self {
+        let unix_ms = time.as_millis() as u64;
+        let fraction = ((time.subsec_nanos() % 1_000_000) as u64 * 4096 / 1_000_000) as u16;
+        let fraction = fraction.to_be_bytes();
+        Self::v7_from_parts(unix_ms, [
+            fraction[0], fraction[1], rand[0], rand[1], rand[2], rand[3], rand[4], rand[5], rand[6], rand[7],
+        ])
+    }
+
+    #[inline]
+    ///Constructs UUID `v8` from `custom` layout, overwriting only version and variant bits.
+    ///
+    ///Remaining 122 bits are preserved as they are.
+    pub const fn v8(custom: [u8; UUID_SIZE]) -> Self {
+        Self::from_bytes(custom).set_version(Version::Custom).set_variant()
+    }
+
+    #[inline]
+    ///Constructs UUID `v8` from unix timestamp in milliseconds followed by custom payload.
+    ///
+    ///Layout is the same as `v7`, which makes UUIDs sortable by time.
+    ///Only lower 48 bits of `unix_ms` are used, while `payload` is packed into the remaining 74 bits,
+    ///skipping version and variant, hence only first 74 bits of `payload` are used, and missing bits are zero.
+    pub const fn v8_with_time(unix_ms: u64, payload: &[u8]) -> Self {
+        const PAYLOAD_SIZE: usize = 10;
+        const RAND_B_BITS: u32 = 62;
+
+        let mut bits = 0u128;
+        let mut idx = 0;
+        while idx < PAYLOAD_SIZE {
+            bits <<= 8;
+            if idx < payload.len() {
+                bits |= payload[idx] as u128;
+            }
+            idx += 1;
+        }
+        //80 bits of payload are cut down to 74 bits
+        bits >>= 6;
+
+        let rand_a = (bits >> RAND_B_BITS) & 0x0FFF;
+        let rand_b = bits & ((1 << RAND_B_BITS) - 1);
+        let uuid = ((unix_ms as u128 & 0xFFFF_FFFF_FFFF) << 80)
+                   | ((Version::Custom as u128) << 76)
+                   | (rand_a << 64)
+                   | (0b10 << RAND_B_BITS)
+                   | rand_b;
+
+        Self::from_bytes(uuid.to_be_bytes())
+    }
+
+    #[cfg(any(feature = "osrng", feature = "prng"))]
+    ///Generates unique UUID using the best algorithm available with enabled features.
+    ///
+    ///In order of preference:
+    ///
+    ///- `v7` when `std` is enabled, using `osrng` or `prng` as source of random;
+    ///- `v4` using OS RNG when `osrng` is enabled;
+    ///- `v4` using PRNG when `prng` is enabled.
+    ///
+    ///Only available when either `osrng` or `prng` feature is enabled.
+    pub fn new() -> Self {
+        #[cfg(all(feature = "std", feature = "osrng"))]
+        {
+            Self::v7_per_thread()
+        }
+        #[cfg(all(feature = "std", not(feature = "osrng")))]
+        {
+            Self::v7_now()
+        }
+        #[cfg(all(not(feature = "std"), feature = "osrng"))]
+        {
+            Self::v4()
+        }
+        #[cfg(all(not(feature = "std"), not(feature = "osrng")))]
+        {
+            Self::v4_prng()
+        }
+    }
+
+    #[cfg(any(feature = "osrng", feature = "prng"))]
+    ///Generates UUID `v7` from unix `time`, filling the rest with random.
+    ///
+    ///Uses OS RNG when `osrng` is enabled, otherwise PRNG.
+    ///
+    ///Only available when either `osrng` or `prng` feature is enabled.
+    pub fn v7(time: time::Duration) -> Self {
+        let rand = random_bytes();
+        Self::v7_from_parts(time.as_millis() as u64, [
+            rand[0], rand[1], rand[2], rand[3], rand[4], rand[5], rand[6], rand[7], rand[8], rand[9],
+        ])
+    }
+
+    #[cfg(any(feature = "osrng", feature = "prng"))]
+    ///Generates UUID `v7` from unix `time`, using sub-millisecond precision in place of `rand_a`.
+    ///
+    ///Refer to `v7_precise_from_parts` for details.
+    ///
+    ///Uses OS RNG when `osrng` is enabled, otherwise PRNG.
+    ///
+    ///Only available when either `osrng` or `prng` feature is enabled.
+    pub fn v7_precise(time: time::Duration) -> Self {
+        let rand = random_bytes();
+        Self::v7_precise_from_parts(time, [rand[0], rand[1], rand[2], rand[3], rand[4], rand[5], rand[6], rand[7]])
+    }
+
+    #[cfg(all(feature = "std", any(feature = "osrng", feature = "prng")))]
+    ///Generates UUID `v7` using current time.
+    ///
+    ///Only available when `std` and either `osrng` or `prng` feature is enabled.
+    pub fn v7_now() -> Self {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).expect("System time is behind unix epoch");
+        Self::v7(now)
+    }
+
+    #[cfg(all(feature = "std", any(feature = "osrng", feature = "prng")))]
+    ///Generates squuid, which is UUID `v4` with first 32 bits replaced by unix time in seconds.
+    ///
+    ///This makes UUIDs roughly sortable by creation time, similar to Datomic's squuids.
+    ///
+    ///Only available when `std` and either `osrng` or `prng` feature is enabled.
+    pub fn squuid() -> Self {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).expect("System time is behind unix epoch");
+        let secs = (now.as_secs() as u32).to_be_bytes();
+        let mut bytes = random_bytes();
+        bytes[..4].copy_from_slice(&secs);
+        Self::v4_from(bytes)
+    }
+
+    #[cfg(any(feature = "osrng", feature = "prng"))]
+    ///Generates UUID, uniformly distributed within `range`, ordered as big endian integers.
+    ///
+    ///Result is raw value within keyspace, hence neither version nor variant is set.
+    ///
+    ///Uses OS RNG when `osrng` is enabled, otherwise PRNG.
+    ///
+    ///Only available when either `osrng` or `prng` feature is enabled.
+    ///
+    ///## Panics
+    ///
+    ///If `range` is empty.
+    pub fn random_within(range: core::ops::Range<Uuid>) -> Self {
+        let start = u128::from(range.start);
+        let end = u128::from(range.end);
+        assert!(start < end, "Range is empty");
+
+        let span = end - start;
+        //Reject values from incomplete last span to avoid bias.
+        let zone = u128::MAX - (u128::MAX - span + 1) % span;
+        loop {
+            let random = u128::from_ne_bytes(random_bytes());
+            if random <= zone {
+                return Self::from_bytes((start + random % span).to_be_bytes());
+            }
+        }
+    }
+
+    #[cfg(all(feature = "std", feature = "osrng"))]
+    ///Generates UUID `v7` using thread local state, guaranteeing monotonic order within the thread.
+    ///
+    ///Layout is:
+    ///
+    ///- 48 bits of unix timestamp in milliseconds;
+    ///- 12 bits of counter in place of `rand_a`, which is reset each millisecond;
+    ///- 16 bits of thread number, assigned on first use within thread, in the middle of `rand_b`;
+    ///- Rest of `rand_b` is filled with thread local PRNG, seeded from OS RNG.
+    ///
+    ///When counter overflows within the same millisecond, timestamp is advanced by one millisecond.
+    ///Shared state is only accessed on thread start and exit, which makes this generator suitable for
+    ///highly concurrent usage, while thread number ensures uniqueness across threads.
+    ///
+    ///Thread number is released on thread exit for reuse by new threads, hence it is unique among
+    ///live threads.
+    ///
+    ///Only available when `std` and `osrng` features are enabled.
+    ///
+    ///## Panics
+    ///
+    ///If more than 65536 threads use this generator at the same time.
+    pub fn v7_per_thread() -> Self {
+        use core::cell::Cell;
+        use core::sync::atomic::{AtomicU32, Ordering};
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        const COUNTER_MAX: u16 = 0x0FFF;
+        const WORD_BITS: usize = 32;
+
+        //Bitmap of thread numbers in use by live threads
+        #[allow(clippy::declare_interior_mutable_const)]
+        const FREE: AtomicU32 = AtomicU32::new(0);
+        static THREAD_NUMS: [AtomicU32; (u16::MAX as usize + 1) / WORD_BITS] = [FREE; (u16::MAX as usize + 1) / WORD_BITS];
+
+        struct ThreadNum(u16);
+
+        impl ThreadNum {
+            fn acquire() -> Self {
+                for (idx, word) in THREAD_NUMS.iter().enumerate() {
+                    let mut value = word.load(Ordering::Relaxed);
+                    while value != u32::MAX {
+                        let bit = (!value).trailing_zeros();
+                        value = word.fetch_or(1 << bit, Ordering::AcqRel);
+                        if value & (1 << bit) == 0 {
+                            return Self((idx * WORD_BITS) as u16 + bit as u16);
+                        }
+                    }
+                }
+
+                panic!("Thread numbers are exhausted");
+            }
+        }
+
+        impl Drop for ThreadNum {
+            #[inline]
+            fn drop(&mut self) {
+                let idx = self.0 as usize / WORD_BITS;
+                let bit = self.0 as usize % WORD_BITS;
+                THREAD_NUMS[idx].fetch_and(!(1 << bit), Ordering::AcqRel);
+            }
+        }
+
+        struct State {
+            thread: ThreadNum,
+            last_ms: Cell<u64>,
+            counter: Cell<u16>,
+            rng: Cell<u64>,
+        }
+
+        std::thread_local! {
+            static STATE: State = {
+                let mut seed = [0; 8];
+                if let Err(error) = getrandom::getrandom(&mut seed) {
+                    panic!("OS RNG is not available for use: {}", error)
+                }
+
+                State {
+                    thread: ThreadNum::acquire(),
+                    last_ms: Cell::new(0),
+                    counter: Cell::new(0),
+                    rng: Cell::new(u64::from_ne_bytes(seed)),
+                }
+            };
+        }
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).expect("System time is behind unix epoch");
+        let now = now.as_millis() as u64;
+
+        STATE.with(|state| {
+            let mut unix_ms = state.last_ms.get();
+            let mut counter = state.counter.get();
+            if now > unix_ms {
+                unix_ms = now;
+                counter = 0;
+            } else if counter == COUNTER_MAX {
+                unix_ms += 1;
+                counter = 0;
+            } else {
+                counter += 1;
+            }
+            state.last_ms.set(unix_ms);
+            state.counter.set(counter);
+
+            //splitmix64
+            let rng = state.rng.get().wrapping_add(0x9E37_79B9_7F4A_7C15);
+            state.rng.set(rng);
+            let mut rand = rng;
+            rand = (rand ^ (rand >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            rand = (rand ^ (rand >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            rand ^= rand >> 31;
+
+            let counter = counter.to_be_bytes();
+            let thread = state.thread.0.to_be_bytes();
+            let rand = rand.to_be_bytes();
+            Self::v7_from_parts(unix_ms, [
+                counter[0], counter[1],
+                rand[0], thread[0], thread[1], rand[1], rand[2], rand[3], rand[4], rand[5],
+            ])
+        })
+    }
+
+    #[cfg(all(feature = "std", target_has_atomic = "64", any(feature = "osrng", feature = "prng")))]
+    ///Generates UUID `v7` using current time, strictly increasing across all threads of process.
+    ///
+    ///Layout:
+    ///- 48 bits of unix timestamp in milliseconds;
+    ///- 12 bits of counter in place of `rand_a`, which is reset each millisecond;
+    ///- `rand_b` is filled with random.
+    ///
+    ///Timestamp and counter are stored within single global atomic, updated via CAS, hence each
+    ///returned UUID is greater than every UUID returned before it.
+    ///When counter overflows within the same millisecond, timestamp is advanced by one millisecond.
+    ///
+    ///Only available when `std` and either `osrng` or `prng` feature is enabled.
+    pub fn v7_monotonic() -> Self {
+        use core::sync::atomic::{AtomicU64, Ordering};
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        const COUNTER_BITS: u32 = 12;
+
+        //Unix timestamp in milliseconds, followed by counter
+        static LAST: AtomicU64 = AtomicU64::new(0);
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).expect("System time is behind unix epoch");
+        let now = (now.as_millis() as u64) << COUNTER_BITS;
+
+        let mut last = LAST.load(Ordering::Relaxed);
+        let next = loop {
+            //Counter overflow carries into timestamp
+            let next = if now > last { now } else { last + 1 };
+            match LAST.compare_exchange_weak(last, next, Ordering::Relaxed, Ordering::Relaxed) {
+                Ok(_) => break next,
+                Err(actual) => last = actual,
+            }
+        };
+
+        let counter = ((next & 0x0FFF) as u16).to_be_bytes();
+        let rand = random_bytes();
+        Self::v7_from_parts(next >> COUNTER_BITS, [
+            counter[0], counter[1],
+            rand[0], rand[1], rand[2], rand[3], rand[4], rand[5], rand[6], rand[7],
+        ])
+    }
+
+    #[cfg(feature = "std")]
+    ///Returns fingerprint of the current process and thread, as embedded by `v8_fingerprint`.
+    ///
+    ///Only available when `std` feature is enabled.
+    pub fn thread_fingerprint() -> u32 {
+        use core::hash::{Hash, Hasher};
+
+        let mut hasher = Fnv(fnv1a(FNV_OFFSET_BASIS, &std::process::id().to_be_bytes()));
+        std::thread::current().id().hash(&mut hasher);
+        let hash = hasher.finish();
+        (hash ^ (hash >> 32)) as u32
+    }
+
+    #[cfg(feature = "std")]
+    ///Generates UUID `v8`, embedding fingerprint of the current process and thread.
+    ///
+    ///UUID has the same layout as `v8_with_time`, using current time and payload consisting of:
+    ///
+    ///- 32 bits of `thread_fingerprint`;
+    ///- 32 bits of process-wide counter, incremented on each call;
+    ///
+    ///This is intended for debugging purposes, allowing to attribute UUID to the process/thread that generated it.
+    ///
+    ///Only available when `std` feature is enabled.
+    pub fn v8_fingerprint() -> Self {
+        use core::sync::atomic::{AtomicU32, Ordering};
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).expect("System time is behind unix epoch");
+        let fingerprint = Self::thread_fingerprint().to_be_bytes();
+        let counter = COUNTER.fetch_add(1, Ordering::Relaxed).to_be_bytes();
+        Self::v8_with_time(now.as_millis() as u64, &[
+            fingerprint[0], fingerprint[1], fingerprint[2], fingerprint[3],
+            counter[0], counter[1], counter[2], counter[3],
+        ])
+    }
+
+    #[inline]
+    ///Returns fingerprint embedded by `v8_fingerprint`.
+    ///
+    ///`None` is returned for versions other than `v8`, but it is up to user to know that `v8` layout
+    ///is produced by `v8_fingerprint`.
+    pub const fn fingerprint(&self) -> Option<u32> {
+        if self.is_version(Version::Custom) {
+            let uuid = u128::from_be_bytes(self.data);
+            let rand_a = (uuid >> 64) & 0x0FFF;
+            let rand_b = uuid & ((1 << 62) - 1);
+            Some((((rand_a << 62) | rand_b) >> 42) as u32)
+        } else {
+            None
+        }
     }
 
     #[cfg(feature = "sha1")]
@@ -444,103 +2159,560 @@ impl Uuid {
                                      .const_update(name)
                                      .const_result();
 
-        Self::from_bytes([
-            sha1[0], sha1[1], sha1[2], sha1[3], sha1[4], sha1[5], sha1[6], sha1[7],
-            sha1[8], sha1[9], sha1[10], sha1[11], sha1[12], sha1[13], sha1[14], sha1[15],
-        ]).set_variant().set_version(Version::Sha1)
+        Self::from_bytes([
+            sha1[0], sha1[1], sha1[2], sha1[3], sha1[4], sha1[5], sha1[6], sha1[7],
+            sha1[8], sha1[9], sha1[10], sha1[11], sha1[12], sha1[13], sha1[14], sha1[15],
+        ]).set_variant().set_version(Version::Sha1)
+    }
+
+    #[cfg(feature = "sha1")]
+    #[inline(always)]
+    ///Generates UUID `v5` from textual `name`.
+    ///
+    ///Only available when `sha1` feature is enabled.
+    pub const fn v5_str(namespace: Uuid, name: &str) -> Self {
+        Self::v5(namespace, name.as_bytes())
+    }
+
+    #[cfg(feature = "sha1")]
+    #[inline(always)]
+    ///Generates UUID `v5` from textual `name`, using `self` as namespace.
+    ///
+    ///Only available when `sha1` feature is enabled.
+    pub const fn name_uuid(&self, name: &str) -> Self {
+        Self::v5(*self, name.as_bytes())
+    }
+
+    #[cfg(feature = "sha256")]
+    ///Generates name based UUID `v8` by using `sha256` hasher, as described in RFC9562 appendix.
+    ///
+    ///Only available when `sha256` feature is enabled.
+    pub const fn v8_sha256(namespace: Uuid, name: &[u8]) -> Self {
+        let hash = lhash::Sha256::new().const_update(&namespace.data)
+                                       .const_update(name)
+                                       .const_result();
+
+        Self::from_bytes([
+            hash[0], hash[1], hash[2], hash[3], hash[4], hash[5], hash[6], hash[7],
+            hash[8], hash[9], hash[10], hash[11], hash[12], hash[13], hash[14], hash[15],
+        ]).set_variant().set_version(Version::Custom)
+    }
+
+    #[inline]
+    ///Adds variant byte to the corresponding field.
+    ///
+    ///This sets RFC4122 variant, use `set_variant_of` to set other variants.
+    ///
+    ///Useful when user is supplied with random bytes, and wants to create UUID from it.
+    pub const fn set_variant(mut self) -> Self {
+        self.data[8] = (self.data[8] & 0x3f) | 0x80;
+        self
+    }
+
+    #[inline]
+    ///Sets specified `variant`, preserving the rest of bits.
+    pub const fn set_variant_of(mut self, variant: Variant) -> Self {
+        self.data[8] = match variant {
+            Variant::Ncs => self.data[8] & 0x7f,
+            Variant::Rfc4122 => (self.data[8] & 0x3f) | 0x80,
+            Variant::Microsoft => (self.data[8] & 0x1f) | 0xc0,
+            Variant::Future => (self.data[8] & 0x1f) | 0xe0,
+        };
+        self
+    }
+
+    #[inline]
+    ///Adds version byte to the corresponding field.
+    ///
+    ///Useful when user is supplied with random bytes, and wants to create UUID from it.
+    ///
+    ///`Version::Unknown` has no numeric value, and leaves version unchanged.
+    pub const fn set_version(mut self, version: Version) -> Self {
+        if let Some(version) = version.as_u8() {
+            self.data[6] = (self.data[6] & 0x0f) | (version << 4);
+        }
+        self
+    }
+
+    ///Creates new instance by parsing `-` separated input of fixed size.
+    ///
+    ///Same as `parse_ascii_bytes`, but without need to dispatch on length.
+    pub const fn parse_fixed(input: &[u8; 36]) -> Result<Self, ParseError> {
+        if let Err(error) = validate_groups(input) {
+            return Err(error);
+        }
+
+        Ok(Self::from_bytes([
+            hex_to_byte_try!(input, 0),
+            hex_to_byte_try!(input, 2),
+            hex_to_byte_try!(input, 4),
+            hex_to_byte_try!(input, 6),
+            //+1 for `-`
+            hex_to_byte_try!(input, 8 + 1),
+            hex_to_byte_try!(input, 10 + 1),
+            //+1 for `-`
+            hex_to_byte_try!(input, 12 + 2),
+            hex_to_byte_try!(input, 14 + 2),
+            //+1 for `-`
+            hex_to_byte_try!(input, 16 + 3),
+            hex_to_byte_try!(input, 18 + 3),
+            //+1 for `-`
+            hex_to_byte_try!(input, 20 + 4),
+            hex_to_byte_try!(input, 22 + 4),
+            hex_to_byte_try!(input, 24 + 4),
+            hex_to_byte_try!(input, 26 + 4),
+            hex_to_byte_try!(input, 28 + 4),
+            hex_to_byte_try!(input, 30 + 4),
+        ]))
+    }
+
+    ///Creates new instance by parsing simple input of fixed size, without separators.
+    ///
+    ///Same as `parse_ascii_bytes`, but without need to dispatch on length.
+    pub const fn parse_fixed_simple(input: &[u8; 32]) -> Result<Self, ParseError> {
+        Ok(Self::from_bytes([
+            hex_to_byte_try!(input, 0),
+            hex_to_byte_try!(input, 2),
+            hex_to_byte_try!(input, 4),
+            hex_to_byte_try!(input, 6),
+            hex_to_byte_try!(input, 8),
+            hex_to_byte_try!(input, 10),
+            hex_to_byte_try!(input, 12),
+            hex_to_byte_try!(input, 14),
+            hex_to_byte_try!(input, 16),
+            hex_to_byte_try!(input, 18),
+            hex_to_byte_try!(input, 20),
+            hex_to_byte_try!(input, 22),
+            hex_to_byte_try!(input, 24),
+            hex_to_byte_try!(input, 26),
+            hex_to_byte_try!(input, 28),
+            hex_to_byte_try!(input, 30),
+        ]))
+    }
+
+    ///Creates new instance by parsing provided bytes.
+    ///
+    ///Use this when you want to avoid performing utf-8 checks and directly feed bytes.
+    ///As long as supplied bytes contain valid ascii characters it will parse successfully.
+    ///Otherwise it shall fail with invalid character.
+    ///
+    ///Supports simple sequence of characters, `-` separated, braced `{...}` and URN `urn:uuid:...` forms.
+    ///URN prefix is case insensitive.
+    ///
+    ///Position of invalid character is reported relative to the start of `input`, including prefix.
+    pub const fn parse_ascii_bytes(input: &[u8]) -> Result<Self, ParseError> {
+        if input.len() == URN_PREFIX.len() + StrBuf::capacity() {
+            let mut idx = 0;
+            while idx < URN_PREFIX.len() {
+                if input[idx].to_ascii_lowercase() != URN_PREFIX[idx] {
+                    return Err(ParseError::InvalidByte(input[idx], idx));
+                }
+                idx += 1;
+            }
+
+            let (_, inner) = input.split_at(URN_PREFIX.len());
+            match Self::parse_ascii_bytes(inner) {
+                Ok(result) => Ok(result),
+                Err(error) => Err(error.shift(inner, URN_PREFIX.len())),
+            }
+        } else if input.len() == StrBuf::capacity() + 2 {
+            match input {
+                [b'{', inner @ .., b'}'] => match Self::parse_ascii_bytes(inner) {
+                    Ok(result) => Ok(result),
+                    Err(error) => Err(error.shift(inner, 1)),
+                },
+                [b'{', .., last] => Err(ParseError::InvalidByte(*last, input.len() - 1)),
+                [first, ..] => Err(ParseError::InvalidByte(*first, 0)),
+                [] => Err(ParseError::InvalidLength(0)),
+            }
+        } else if input.len() == StrBuf::capacity() {
+            //Length is checked above
+            Self::parse_fixed(unsafe {
+                &*(input.as_ptr() as *const [u8; 36])
+            })
+        } else if input.len() == StrBuf::capacity() - 4 {
+            //Length is checked above
+            Self::parse_fixed_simple(unsafe {
+                &*(input.as_ptr() as *const [u8; 32])
+            })
+        } else {
+            Err(ParseError::InvalidLength(input.len()))
+        }
+    }
+
+    ///Checks whether `input` is valid UUID, without constructing it.
+    ///
+    ///Accepts the same forms as `parse_ascii_bytes`.
+    pub const fn is_valid_bytes(input: &[u8]) -> bool {
+        match input.len() {
+            36 => {
+                input[8] == SEP && input[13] == SEP && input[18] == SEP && input[23] == SEP
+                && is_hex_range(input, 0, 8) && is_hex_range(input, 9, 13) && is_hex_range(input, 14, 18)
+                && is_hex_range(input, 19, 23) && is_hex_range(input, 24, 36)
+            },
+            32 => is_hex_range(input, 0, 32),
+            38 => match input {
+                [b'{', inner @ .., b'}'] => Self::is_valid_bytes(inner),
+                _ => false,
+            },
+            45 => {
+                let mut idx = 0;
+                while idx < URN_PREFIX.len() {
+                    if input[idx].to_ascii_lowercase() != URN_PREFIX[idx] {
+                        return false;
+                    }
+                    idx += 1;
+                }
+                Self::is_valid_bytes(input.split_at(URN_PREFIX.len()).1)
+            },
+            _ => false,
+        }
+    }
+
+    #[inline(always)]
+    ///Checks whether `input` is valid UUID, without constructing it.
+    ///
+    ///Accepts the same forms as `parse_str`.
+    pub const fn is_valid(input: &str) -> bool {
+        Self::is_valid_bytes(input.as_bytes())
+    }
+
+    #[inline(always)]
+    ///Creates new instance by parsing provided string.
+    ///
+    ///Supports simple sequence of characters, `-` separated, braced `{...}` and URN `urn:uuid:...` forms.
+    pub const fn parse_str(input: &str) -> Result<Self, ParseError> {
+        Self::parse_ascii_bytes(input.as_bytes())
+    }
+
+    ///Creates new instance by parsing UTF-16 encoded string, as used by Windows wide strings.
+    ///
+    ///Accepts the same forms as `parse_str`.
+    ///Code units outside of ASCII are reported as invalid character `0xff`.
+    pub const fn parse_utf16(input: &[u16]) -> Result<Self, ParseError> {
+        const MAX_LEN: usize = URN_PREFIX.len() + StrBuf::capacity();
+
+        if input.len() > MAX_LEN {
+            return Err(ParseError::InvalidLength(input.len()));
+        }
+
+        let mut buffer = [0u8; MAX_LEN];
+        let mut idx = 0;
+        while idx < input.len() {
+            buffer[idx] = match input[idx] {
+                unit @ 0..=0x7f => unit as u8,
+                _ => 0xff,
+            };
+            idx += 1;
+        }
+
+        let (text, _) = buffer.split_at(input.len());
+        Self::parse_ascii_bytes(text)
+    }
+
+    #[inline]
+    ///Creates new instance by parsing provided string, swapping byte order of first 3 fields.
+    ///
+    ///Resulting bytes are in mixed endian layout of `GUID` as stored in memory by Windows,
+    ///same as `Uuid::parse_str(input)?.to_bytes_le()`.
+    pub const fn parse_guid_str(input: &str) -> Result<Self, ParseError> {
+        match Self::parse_str(input) {
+            Ok(result) => Ok(Self::from_bytes_le(result.data)),
+            Err(error) => Err(error),
+        }
+    }
+
+    ///Creates new instance by parsing provided string, ignoring surrounding noise.
+    ///
+    ///Skips surrounding ASCII whitespace and then optional matching single or double quotes,
+    ///before parsing the rest as `parse_str`.
+    ///
+    ///Position of invalid character is reported relative to the start of `input`.
+    pub const fn parse_trimmed(input: &str) -> Result<Self, ParseError> {
+        let input = input.as_bytes();
+        let mut start = skip_whitespace(input, 0);
+        let mut end = input.len();
+        while end > start && input[end - 1].is_ascii_whitespace() {
+            end -= 1;
+        }
+
+        if end - start >= 2 && (input[start] == b'"' || input[start] == b'\'') && input[end - 1] == input[start] {
+            start += 1;
+            end -= 1;
+        }
+
+        let (inner, _) = input.split_at(end);
+        let (_, inner) = inner.split_at(start);
+        match Self::parse_ascii_bytes(inner) {
+            Ok(result) => Ok(result),
+            Err(ParseError::InvalidLength(_)) => Err(ParseError::InvalidLength(input.len())),
+            Err(error) => Err(error.shift(inner, start)),
+        }
+    }
+
+    ///Parses `sep` separated list of UUIDs from `input` into `out`, returning number of parsed UUIDs.
+    ///
+    ///Each UUID can be in any form supported by `parse_ascii_bytes`, and trailing separator is allowed.
+    ///
+    ///Position of invalid character is reported relative to the start of `input`.
+    ///UUID of invalid length is reported as unexpected separator after it, or as invalid length of `input`, if it is the last one.
+    ///Similarly when `out` is full, separator after last parsed UUID is reported as unexpected.
+    pub fn parse_many(input: &[u8], sep: u8, out: &mut [Uuid]) -> Result<usize, ParseError> {
+        let mut count = 0;
+        let mut offset = 0;
+        while offset < input.len() {
+            if count == out.len() {
+                return match offset {
+                    0 => Err(ParseError::InvalidLength(input.len())),
+                    _ => Err(ParseError::InvalidByte(sep, offset - 1)),
+                };
+            }
+
+            let end = match input[offset..].iter().position(|byte| *byte == sep) {
+                Some(len) => offset + len,
+                None => input.len(),
+            };
+            let item = &input[offset..end];
+            out[count] = match Self::parse_ascii_bytes(item) {
+                Ok(uuid) => uuid,
+                Err(ParseError::InvalidLength(_)) if end < input.len() => return Err(ParseError::InvalidByte(sep, end)),
+                Err(ParseError::InvalidLength(_)) => return Err(ParseError::InvalidLength(input.len())),
+                Err(error) => return Err(error.shift(item, offset)),
+            };
+            count += 1;
+            offset = end + 1;
+        }
+
+        Ok(count)
+    }
+
+    #[inline(always)]
+    ///Parses UUID from base64 text, produced by either `to_base64` or `to_base64url`.
+    ///
+    ///Both standard (`+/`) and URL safe (`-_`) alphabets are accepted, as well as trailing `==` padding.
+    ///
+    ///Bytes are expected in RFC order, while .NET `Convert.ToBase64String(guid.ToByteArray())` produces
+    ///mixed endian layout, which requires `from_bytes_le` conversion.
+    pub const fn parse_base64(input: &str) -> Result<Self, DecodeError> {
+        decode_base64(input.as_bytes(), BASE64, BASE64_URL)
+    }
+
+    #[inline(always)]
+    ///Parses UUID from URL safe base64 text, produced by `to_base64url`.
+    ///
+    ///Unlike `parse_base64`, only URL safe alphabet is accepted.
+    ///Trailing `==` padding is accepted.
+    pub const fn parse_base64url(input: &str) -> Result<Self, DecodeError> {
+        decode_base64(input.as_bytes(), BASE64_URL, BASE64_URL)
+    }
+
+    ///Parses UUID from Crockford's base32 text, produced by `to_base32`.
+    ///
+    ///Parsing is case insensitive, and `I`, `L` and `O` are accepted as `1`, `1` and `0` respectively.
+    pub const fn parse_base32(input: &str) -> Result<Self, DecodeError> {
+        let input = input.as_bytes();
+        if input.len() != BASE32_LEN {
+            return Err(DecodeError::InvalidLength(input.len()));
+        }
+
+        let mut value = 0u128;
+        let mut idx = 0;
+        while idx < input.len() {
+            let chr = input[idx];
+            let digit = match chr.to_ascii_uppercase() {
+                upper @ b'0'..=b'9' => upper - b'0',
+                b'O' => 0,
+                b'I' | b'L' => 1,
+                upper @ b'A'..=b'H' => upper - b'A' + 10,
+                upper @ (b'J' | b'K') => upper - b'J' + 18,
+                upper @ (b'M' | b'N') => upper - b'M' + 20,
+                upper @ b'P'..=b'T' => upper - b'P' + 22,
+                upper @ b'V'..=b'Z' => upper - b'V' + 27,
+                _ => return Err(DecodeError::InvalidDigit(chr, idx)),
+            };
+            //First digit holds only 3 bits of value
+            if idx == 0 && digit > 7 {
+                return Err(DecodeError::InvalidDigit(chr, idx));
+            }
+            value = (value << 5) | digit as u128;
+            idx += 1;
+        }
+
+        Ok(Self::from_bytes(value.to_be_bytes()))
+    }
+
+    ///Parses UUID from base58 text, produced by `to_base58`.
+    pub const fn parse_base58(input: &str) -> Result<Self, DecodeError> {
+        let input = input.as_bytes();
+        if input.len() != BASE58_LEN {
+            return Err(DecodeError::InvalidLength(input.len()));
+        }
+
+        let mut value = 0u128;
+        let mut idx = 0;
+        while idx < input.len() {
+            let chr = input[idx];
+            let digit = match chr {
+                b'1'..=b'9' => chr - b'1',
+                b'A'..=b'H' => chr - b'A' + 9,
+                b'J'..=b'N' => chr - b'J' + 17,
+                b'P'..=b'Z' => chr - b'P' + 22,
+                b'a'..=b'k' => chr - b'a' + 33,
+                b'm'..=b'z' => chr - b'm' + 44,
+                chr => return Err(DecodeError::InvalidDigit(chr, idx)),
+            };
+            value = match value.checked_mul(58) {
+                Some(value) => match value.checked_add(digit as u128) {
+                    Some(value) => value,
+                    None => return Err(DecodeError::Overflow(idx)),
+                },
+                None => return Err(DecodeError::Overflow(idx)),
+            };
+            idx += 1;
+        }
+
+        Ok(Self::from_bytes(value.to_be_bytes()))
     }
 
-    #[inline]
-    ///Adds variant byte to the corresponding field.
-    ///
-    ///This implementation only cares about RFC4122, there is no option to set other variant.
+    ///Creates new instance by parsing provided string, detecting its form.
     ///
-    ///Useful when user is supplied with random bytes, and wants to create UUID from it.
-    pub const fn set_variant(mut self) -> Self {
-        self.data[8] = (self.data[8] & 0x3f) | 0x80;
-        self
+    ///In addition to forms supported by `parse_str`, it accepts simple form within `{...}`,
+    ///either form within parentheses `(...)` and simple form with URN prefix.
+    pub const fn parse_any(input: &str) -> Result<Self, ParseError> {
+        let input = input.as_bytes();
+        let (offset, inner) = match input {
+            [b'{', inner @ .., b'}'] | [b'(', inner @ .., b')'] => (1, inner),
+            _ if input.len() > URN_PREFIX.len() => {
+                let mut idx = 0;
+                while idx < URN_PREFIX.len() && input[idx].to_ascii_lowercase() == URN_PREFIX[idx] {
+                    idx += 1;
+                }
+
+                match idx == URN_PREFIX.len() {
+                    true => (URN_PREFIX.len(), input.split_at(URN_PREFIX.len()).1),
+                    false => (0, input),
+                }
+            },
+            _ => (0, input),
+        };
+
+        if offset > 0 && inner.len() != StrBuf::capacity() && inner.len() != StrBuf::capacity() - 4 {
+            return Err(ParseError::InvalidLength(input.len()));
+        }
+
+        match Self::parse_ascii_bytes(inner) {
+            Ok(result) => Ok(result),
+            Err(ParseError::InvalidLength(_)) => Err(ParseError::InvalidLength(input.len())),
+            Err(error) => Err(error.shift(inner, offset)),
+        }
     }
 
-    #[inline]
-    ///Adds version byte to the corresponding field.
+    ///Parses UUID from .NET `X` format, i.e. `{0x6ba7b810,0x9dad,0x11d1,{0x80,0xb4,0x00,0xc0,0x4f,0xd4,0x30,0xc8}}`.
     ///
-    ///Useful when user is supplied with random bytes, and wants to create UUID from it.
-    pub const fn set_version(mut self, version: Version) -> Self {
-        self.data[6] = (self.data[6] & 0x0f) | ((version as u8) << 4);
-        self
+    ///As in C struct initializers, whitespace is allowed between elements and leading zeroes of values
+    ///can be omitted.
+    pub const fn parse_dotnet_x(input: &str) -> Result<Self, ParseError> {
+        let input = input.as_bytes();
+        let mut data = [0u8; UUID_SIZE];
+        let mut idx = const_try!(expect_byte(input, 0, b'{'));
+
+        let (value, next) = const_try!(expect_hex_value(input, idx, 8));
+        let value = value.to_be_bytes();
+        data[0] = value[0];
+        data[1] = value[1];
+        data[2] = value[2];
+        data[3] = value[3];
+        idx = const_try!(expect_byte(input, next, b','));
+
+        let (value, next) = const_try!(expect_hex_value(input, idx, 4));
+        let value = (value as u16).to_be_bytes();
+        data[4] = value[0];
+        data[5] = value[1];
+        idx = const_try!(expect_byte(input, next, b','));
+
+        let (value, next) = const_try!(expect_hex_value(input, idx, 4));
+        let value = (value as u16).to_be_bytes();
+        data[6] = value[0];
+        data[7] = value[1];
+        idx = const_try!(expect_byte(input, next, b','));
+        idx = const_try!(expect_byte(input, idx, b'{'));
+
+        let mut byte = 8;
+        while byte < UUID_SIZE {
+            if byte > 8 {
+                idx = const_try!(expect_byte(input, idx, b','));
+            }
+            let (value, next) = const_try!(expect_hex_value(input, idx, 2));
+            data[byte] = value as u8;
+            idx = next;
+            byte += 1;
+        }
+
+        idx = const_try!(expect_byte(input, idx, b'}'));
+        idx = const_try!(expect_byte(input, idx, b'}'));
+        idx = skip_whitespace(input, idx);
+        if idx < input.len() {
+            return Err(ParseError::InvalidByte(input[idx], idx));
+        }
+
+        Ok(Self::from_bytes(data))
     }
 
-    ///Creates new instance by parsing provided bytes.
-    ///
-    ///Use this when you want to avoid performing utf-8 checks and directly feed bytes.
-    ///As long as supplied bytes contain valid ascii characters it will parse successfully.
-    ///Otherwise it shall fail with invalid character.
-    ///
-    ///Supports only simple sequence of characters and `-` separated.
-    pub const fn parse_ascii_bytes(input: &[u8]) -> Result<Self, ParseError> {
-        if input.len() == StrBuf::capacity() {
-            if input[8] != SEP {
-                return Err(ParseError::InvalidGroup(1));
-            } else if input[13] != SEP {
-                return Err(ParseError::InvalidGroup(2));
-            } else if input[18] != SEP {
-                return Err(ParseError::InvalidGroup(3));
-            } else if input[23] != SEP {
-                return Err(ParseError::InvalidGroup(4));
+    ///Parses UUID from OID of `2.25` arc, i.e. `2.25.<UUID as decimal integer>`.
+    pub const fn parse_oid_str(input: &str) -> Result<Self, DecodeError> {
+        let input = input.as_bytes();
+        if input.len() <= OID_PREFIX.len() || input.len() > OID_MAX_LEN {
+            return Err(DecodeError::InvalidLength(input.len()));
+        }
+
+        let mut idx = 0;
+        while idx < OID_PREFIX.len() {
+            if input[idx] != OID_PREFIX[idx] {
+                return Err(DecodeError::InvalidDigit(input[idx], idx));
             }
+            idx += 1;
+        }
 
-            Ok(Self::from_bytes([
-                hex_to_byte_try!(input, 0),
-                hex_to_byte_try!(input, 2),
-                hex_to_byte_try!(input, 4),
-                hex_to_byte_try!(input, 6),
-                //+1 for `-`
-                hex_to_byte_try!(input, 8 + 1),
-                hex_to_byte_try!(input, 10 + 1),
-                //+1 for `-`
-                hex_to_byte_try!(input, 12 + 2),
-                hex_to_byte_try!(input, 14 + 2),
-                //+1 for `-`
-                hex_to_byte_try!(input, 16 + 3),
-                hex_to_byte_try!(input, 18 + 3),
-                //+1 for `-`
-                hex_to_byte_try!(input, 20 + 4),
-                hex_to_byte_try!(input, 22 + 4),
-                hex_to_byte_try!(input, 24 + 4),
-                hex_to_byte_try!(input, 26 + 4),
-                hex_to_byte_try!(input, 28 + 4),
-                hex_to_byte_try!(input, 30 + 4),
-            ]))
-        } else if input.len() == StrBuf::capacity() - 4 {
-            Ok(Self::from_bytes([
-                hex_to_byte_try!(input, 0),
-                hex_to_byte_try!(input, 2),
-                hex_to_byte_try!(input, 4),
-                hex_to_byte_try!(input, 6),
-                hex_to_byte_try!(input, 8),
-                hex_to_byte_try!(input, 10),
-                hex_to_byte_try!(input, 12),
-                hex_to_byte_try!(input, 14),
-                hex_to_byte_try!(input, 16),
-                hex_to_byte_try!(input, 18),
-                hex_to_byte_try!(input, 20),
-                hex_to_byte_try!(input, 22),
-                hex_to_byte_try!(input, 24),
-                hex_to_byte_try!(input, 26),
-                hex_to_byte_try!(input, 28),
-                hex_to_byte_try!(input, 30),
-            ]))
-        } else {
-            Err(ParseError::InvalidLength(input.len()))
+        //Arc components cannot have leading zeroes
+        if input[idx] == b'0' && input.len() > idx + 1 {
+            return Err(DecodeError::InvalidDigit(input[idx], idx));
         }
+
+        let mut result = 0u128;
+        while idx < input.len() {
+            let digit = match input[idx] {
+                byt @ b'0'..=b'9' => (byt - b'0') as u128,
+                byt => return Err(DecodeError::InvalidDigit(byt, idx)),
+            };
+            result = match result.checked_mul(10) {
+                Some(result) => match result.checked_add(digit) {
+                    Some(result) => result,
+                    None => return Err(DecodeError::Overflow(idx)),
+                },
+                None => return Err(DecodeError::Overflow(idx)),
+            };
+            idx += 1;
+        }
+
+        Ok(Self::from_bytes(result.to_be_bytes()))
     }
 
-    #[inline(always)]
-    ///Creates new instance by parsing provided string.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    ///Creates owned hyphenated textual representation of UUID.
     ///
-    ///Supports only simple sequence of characters and `-` separated.
-    pub const fn parse_str(input: &str) -> Result<Self, ParseError> {
-        Self::parse_ascii_bytes(input.as_bytes())
+    ///Only available when `alloc` feature is enabled.
+    pub fn to_hyphenated_string(&self) -> alloc::string::String {
+        alloc::string::String::from(self.to_str().as_str())
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    ///Creates owned simple textual representation of UUID, without separators.
+    ///
+    ///Only available when `alloc` feature is enabled.
+    pub fn to_simple_string(&self) -> alloc::string::String {
+        alloc::string::String::from(self.to_simple_str().as_str())
     }
 
     #[inline]
@@ -589,19 +2761,251 @@ impl Uuid {
             TextRepr(StrBuf::from_storage(storage, StrBuf::capacity() as u8))
         }
     }
+
+    #[inline]
+    ///Creates textual representation of UUID with uppercase hex digits in a static buffer.
+    pub const fn to_str_upper(&self) -> TextRepr {
+        encode_hex(&self.data, b"", true, true, b"")
+    }
+
+    #[inline]
+    ///Creates simple textual representation of UUID, without separators, in a static buffer.
+    ///
+    ///Complements `to_str`, as both forms are accepted by `parse_str`.
+    pub const fn to_simple_str(&self) -> TextRepr<32> {
+        encode_hex(&self.data, b"", false, false, b"")
+    }
+
+    #[inline]
+    ///Creates simple textual representation of UUID with uppercase hex digits, without separators, in a static buffer.
+    pub const fn to_simple_str_upper(&self) -> TextRepr<32> {
+        encode_hex(&self.data, b"", false, true, b"")
+    }
+
+    #[inline]
+    ///Creates braced textual representation of UUID, i.e. `{xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx}`, in a static buffer.
+    pub const fn to_braced_str(&self) -> TextRepr<38> {
+        encode_hex(&self.data, b"{", true, false, b"}")
+    }
+
+    #[inline]
+    ///Creates Windows registry textual representation of UUID, i.e. braced with uppercase hex
+    ///digits `{XXXXXXXX-XXXX-XXXX-XXXX-XXXXXXXXXXXX}`, as used for COM CLSID/IID, in a static buffer.
+    pub const fn to_registry_str(&self) -> TextRepr<38> {
+        encode_hex(&self.data, b"{", true, true, b"}")
+    }
+
+    #[inline]
+    ///Creates URN textual representation of UUID, i.e. `urn:uuid:xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`, in a static buffer.
+    pub const fn to_urn_str(&self) -> TextRepr<45> {
+        encode_hex(&self.data, URN_PREFIX, true, false, b"")
+    }
+
+    #[inline]
+    ///Writes hyphenated textual representation into beginning of `out`, returning written text.
+    ///
+    ///Fails if `out` is shorter than 36 bytes.
+    pub fn encode_to<'a>(&self, out: &'a mut [u8]) -> Result<&'a str, EncodeError> {
+        write_hex(&self.data, out, true)
+    }
+
+    #[inline]
+    ///Writes simple textual representation, without separators, into beginning of `out`, returning written text.
+    ///
+    ///Fails if `out` is shorter than 32 bytes.
+    pub fn encode_simple_to<'a>(&self, out: &'a mut [u8]) -> Result<&'a str, EncodeError> {
+        write_hex(&self.data, out, false)
+    }
+
+    ///Writes hyphenated textual representation directly into `out`, without intermediate buffer.
+    pub fn write_to<W: fmt::Write + ?Sized>(&self, out: &mut W) -> fmt::Result {
+        for (idx, byte) in self.data.iter().enumerate() {
+            if idx == 4 || idx == 6 || idx == 8 || idx == 10 {
+                out.write_char(SEP as char)?;
+            }
+            out.write_char(byte_to_hex(*byte, 1) as char)?;
+            out.write_char(byte_to_hex(*byte, 0) as char)?;
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    ///Returns adapter, formatting UUID as hyphenated text.
+    pub const fn hyphenated(self) -> format::Hyphenated {
+        format::Hyphenated::from_uuid(self)
+    }
+
+    #[inline(always)]
+    ///Returns adapter, formatting UUID as simple text, without separators.
+    pub const fn simple(self) -> format::Simple {
+        format::Simple::from_uuid(self)
+    }
+
+    #[inline(always)]
+    ///Returns adapter, formatting UUID as braced text.
+    pub const fn braced(self) -> format::Braced {
+        format::Braced::from_uuid(self)
+    }
+
+    #[inline(always)]
+    ///Returns adapter, formatting UUID as URN.
+    pub const fn urn(self) -> format::Urn {
+        format::Urn::from_uuid(self)
+    }
+
+    #[inline]
+    ///Creates base64 representation of UUID, without padding, in a static buffer.
+    pub const fn to_base64(&self) -> TextRepr<BASE64_LEN> {
+        encode_base64(&self.data, BASE64)
+    }
+
+    #[inline]
+    ///Creates URL safe base64 representation of UUID, without padding, in a static buffer.
+    pub const fn to_base64url(&self) -> TextRepr<BASE64_LEN> {
+        encode_base64(&self.data, BASE64_URL)
+    }
+
+    ///Creates Crockford's base32 representation of UUID in a static buffer.
+    ///
+    ///UUID is encoded as big-endian integer in 26 uppercase characters, same as ULID.
+    pub const fn to_base32(&self) -> TextRepr<BASE32_LEN> {
+        let value = u128::from_be_bytes(self.data);
+        let mut storage = [mem::MaybeUninit::uninit(); BASE32_LEN];
+        let mut idx = 0;
+        while idx < BASE32_LEN {
+            storage[idx] = mem::MaybeUninit::new(BASE32[((value >> (125 - 5 * idx)) & 0x1f) as usize]);
+            idx += 1;
+        }
+
+        unsafe {
+            TextRepr(str_buf::StrBuf::from_storage(storage, BASE32_LEN as u8))
+        }
+    }
+
+    ///Creates base58 representation of UUID in a static buffer.
+    ///
+    ///UUID is encoded as big-endian integer using bitcoin alphabet, left padded with `1` (zero
+    ///digit) to 22 characters, as used by short UUID libraries.
+    pub const fn to_base58(&self) -> TextRepr<BASE58_LEN> {
+        let mut value = u128::from_be_bytes(self.data);
+        let mut storage = [mem::MaybeUninit::uninit(); BASE58_LEN];
+        let mut idx = BASE58_LEN;
+        while idx > 0 {
+            idx -= 1;
+            storage[idx] = mem::MaybeUninit::new(BASE58[(value % 58) as usize]);
+            value /= 58;
+        }
+
+        unsafe {
+            TextRepr(str_buf::StrBuf::from_storage(storage, BASE58_LEN as u8))
+        }
+    }
+
+    #[inline]
+    ///Creates textual representation of UUID, as .NET `Guid.ToString(format)` does, in a static buffer.
+    pub const fn to_dotnet_str(&self, format: format::DotNet) -> TextRepr<DOTNET_MAX_LEN> {
+        match format {
+            format::DotNet::N => encode_hex(&self.data, b"", false, false, b""),
+            format::DotNet::D => encode_hex(&self.data, b"", true, false, b""),
+            format::DotNet::B => encode_hex(&self.data, b"{", true, false, b"}"),
+            format::DotNet::P => encode_hex(&self.data, b"(", true, false, b")"),
+            format::DotNet::X => encode_dotnet_x(&self.data),
+        }
+    }
+
+    #[inline]
+    ///Creates Rust array literal of UUID bytes, i.e. `[0x6b, 0xa7, ...]`, for use in code generation.
+    pub const fn to_rust_literal(&self) -> TextRepr<LITERAL_LEN> {
+        encode_literal(&self.data, b'[', b']')
+    }
+
+    #[inline]
+    ///Creates C array initializer of UUID bytes, i.e. `{0x6b, 0xa7, ...}`, for use in code generation.
+    pub const fn to_c_initializer(&self) -> TextRepr<LITERAL_LEN> {
+        encode_literal(&self.data, b'{', b'}')
+    }
+
+    ///Creates OID of `2.25` arc, i.e. `2.25.<UUID as decimal integer>`, as defined by ITU-T X.667.
+    pub const fn to_oid_str(&self) -> TextRepr<OID_MAX_LEN> {
+        let mut storage = [mem::MaybeUninit::uninit(); OID_MAX_LEN];
+        let mut idx = 0;
+        while idx < OID_PREFIX.len() {
+            storage[idx] = mem::MaybeUninit::new(OID_PREFIX[idx]);
+            idx += 1;
+        }
+
+        let mut digits = [0u8; OID_MAX_LEN - OID_PREFIX.len()];
+        let mut digits_len = 0;
+        let mut value = u128::from_be_bytes(self.data);
+        loop {
+            digits[digits_len] = b'0' + (value % 10) as u8;
+            digits_len += 1;
+            value /= 10;
+            if value == 0 {
+                break;
+            }
+        }
+
+        while digits_len > 0 {
+            digits_len -= 1;
+            storage[idx] = mem::MaybeUninit::new(digits[digits_len]);
+            idx += 1;
+        }
+
+        unsafe {
+            TextRepr(str_buf::StrBuf::from_storage(storage, idx as u8))
+        }
+    }
 }
 
 impl fmt::Debug for Uuid {
-    #[inline(always)]
+    #[inline]
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.write_str(self.to_str().as_str())
+        if fmt.alternate() {
+            let data = &self.data;
+            fmt.debug_struct("Uuid")
+               .field("text", &self.to_str().as_str())
+               .field("version", &self.version())
+               .field("variant", &self.variant())
+               .field("time_low", &format_args!("{:#010x}", u32::from_be_bytes([data[0], data[1], data[2], data[3]])))
+               .field("time_mid", &format_args!("{:#06x}", u16::from_be_bytes([data[4], data[5]])))
+               .field("time_high_and_version", &format_args!("{:#06x}", u16::from_be_bytes([data[6], data[7]])))
+               .field("clock_seq", &format_args!("{:#06x}", u16::from_be_bytes([data[8], data[9]])))
+               .field("node", &format_args!("{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}", data[10], data[11], data[12], data[13], data[14], data[15]))
+               .finish()
+        } else {
+            fmt.write_str(self.to_str().as_str())
+        }
     }
 }
 
 impl fmt::Display for Uuid {
+    #[inline]
+    ///Writes hyphenated textual representation, or braced one when alternate flag `#` is specified.
+    ///
+    ///Respects width, fill and alignment.
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if fmt.alternate() {
+            fmt.pad(self.to_braced_str().as_str())
+        } else {
+            fmt.pad(self.to_str().as_str())
+        }
+    }
+}
+
+impl fmt::LowerHex for Uuid {
     #[inline(always)]
+    ///Writes simple textual representation, without separators.
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.write_str(self.to_str().as_str())
+        fmt.pad(self.to_simple_str().as_str())
+    }
+}
+
+impl fmt::UpperHex for Uuid {
+    #[inline(always)]
+    ///Writes simple textual representation with uppercase hex digits, without separators.
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.pad(self.to_simple_str_upper().as_str())
     }
 }
 
@@ -626,6 +3030,70 @@ impl From<[u8; UUID_SIZE]> for Uuid {
     }
 }
 
+impl From<Uuid> for [u8; UUID_SIZE] {
+    #[inline(always)]
+    fn from(uuid: Uuid) -> Self {
+        uuid.data
+    }
+}
+
+impl From<Uuid> for u128 {
+    #[inline(always)]
+    ///Converts UUID into integer, interpreting its bytes as big-endian.
+    ///
+    ///This preserves order, i.e. comparing integers is the same as comparing UUIDs.
+    fn from(uuid: Uuid) -> Self {
+        u128::from_be_bytes(uuid.data)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Uuid> for alloc::string::String {
+    #[inline(always)]
+    ///Creates owned hyphenated textual representation of UUID.
+    fn from(uuid: Uuid) -> Self {
+        uuid.to_hyphenated_string()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> From<TextRepr<N>> for alloc::string::String {
+    #[inline(always)]
+    fn from(text: TextRepr<N>) -> Self {
+        alloc::string::String::from(text.as_str())
+    }
+}
+
+impl From<&[u8; UUID_SIZE]> for Uuid {
+    #[inline(always)]
+    fn from(bytes: &[u8; UUID_SIZE]) -> Self {
+        Self::from_bytes(*bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for Uuid {
+    type Error = ParseError;
+
+    #[inline]
+    ///Creates UUID from raw bytes, failing if slice is not exactly 16 bytes long.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        match Self::from_slice(bytes) {
+            Some(uuid) => Ok(uuid),
+            None => Err(ParseError::InvalidLength(bytes.len())),
+        }
+    }
+}
+
+impl TryFrom<&str> for Uuid {
+    type Error = ParseError;
+
+    #[inline(always)]
+    ///Parses UUID from text, same as `Uuid::parse_str`.
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Self::parse_str(input)
+    }
+}
+
 impl core::str::FromStr for Uuid {
     type Err = ParseError;
 
@@ -647,13 +3115,144 @@ pub enum ParseError {
     ///Group has invalid len.
     ///
     ///1. Group number;
-    ///3. Actual len;
+    ///2. Actual len;
     InvalidGroupLen(u8, usize),
     ///Invalid character is encountered.
     ///
     ///1. Character byte;
     ///2. Position from 0;
-    InvalidByte(u8, usize)
+    InvalidByte(u8, usize),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+///Kind of [ParseError](enum.ParseError.html), without any details attached.
+pub enum ParseErrorKind {
+    ///Input has invalid length.
+    InvalidLength,
+    ///Group is not followed by separator.
+    InvalidGroup,
+    ///Group has invalid length.
+    InvalidGroupLen,
+    ///Invalid character is encountered.
+    InvalidByte,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+///Describes what parser expected to find, when error happened.
+pub enum Expected {
+    ///Input of either 32 or 36 characters.
+    Length,
+    ///Separator `-`.
+    Separator,
+    ///Group with specified number of characters.
+    GroupLen(usize),
+    ///Hexadecimal digit.
+    HexDigit,
+}
+
+#[inline]
+const fn group_start(group: u8) -> usize {
+    let mut idx = 0;
+    let mut start = 0;
+    while idx + 1 < group as usize && idx < GROUP_LENS.len() {
+        start += GROUP_LENS[idx] + 1;
+        idx += 1;
+    }
+    start
+}
+
+#[inline]
+const fn group_len(group: u8) -> usize {
+    match group {
+        1..=5 => GROUP_LENS[group as usize - 1],
+        _ => 0,
+    }
+}
+
+impl ParseError {
+    //Shifts position of offending character, when `inner` is part of bigger text, starting at `offset`.
+    //
+    //Group errors cannot hold position, hence they are reported as invalid character in place of separator.
+    const fn shift(self, inner: &[u8], offset: usize) -> Self {
+        let pos = match self {
+            ParseError::InvalidByte(byte, pos) => return ParseError::InvalidByte(byte, pos + offset),
+            ParseError::InvalidLength(_) => return self,
+            ParseError::InvalidGroup(group) => group_start(group) + group_len(group),
+            ParseError::InvalidGroupLen(group, len) => match len < group_len(group) {
+                true => group_start(group) + len,
+                false => group_start(group) + group_len(group),
+            },
+        };
+
+        ParseError::InvalidByte(inner[pos], pos + offset)
+    }
+
+    #[inline]
+    ///Returns kind of error.
+    pub const fn kind(&self) -> ParseErrorKind {
+        match self {
+            ParseError::InvalidLength(_) => ParseErrorKind::InvalidLength,
+            ParseError::InvalidGroup(_) => ParseErrorKind::InvalidGroup,
+            ParseError::InvalidGroupLen(_, _) => ParseErrorKind::InvalidGroupLen,
+            ParseError::InvalidByte(_, _) => ParseErrorKind::InvalidByte,
+        }
+    }
+
+    #[inline]
+    ///Returns position of offending character within input, if error is caused by particular character.
+    ///
+    ///For group errors it is position at which separator is expected or found.
+    pub const fn position(&self) -> Option<usize> {
+        match self {
+            ParseError::InvalidLength(_) => None,
+            ParseError::InvalidGroup(group) => Some(group_start(*group) + group_len(*group)),
+            ParseError::InvalidGroupLen(group, len) => Some(group_start(*group) + *len),
+            ParseError::InvalidByte(_, pos) => Some(*pos),
+        }
+    }
+
+    ///Returns number of group (from 1), to which offending character belongs.
+    ///
+    ///Group of invalid character is derived from its position within `-` separated form,
+    ///with separator belonging to preceding group.
+    ///Since position is relative to the whole input, it matches group only for `-` separated input without prefix.
+    pub const fn group(&self) -> Option<u8> {
+        let pos = match self {
+            ParseError::InvalidGroup(group) | ParseError::InvalidGroupLen(group, _) => return Some(*group),
+            ParseError::InvalidLength(_) => return None,
+            ParseError::InvalidByte(_, pos) => *pos,
+        };
+
+        if pos >= StrBuf::capacity() {
+            return None;
+        }
+
+        let mut group = 0;
+        let mut end = 0;
+        while group < GROUP_LENS.len() {
+            end += GROUP_LENS[group] + 1;
+            if pos < end {
+                break;
+            }
+            group += 1;
+        }
+        Some(group as u8 + 1)
+    }
+
+    #[inline]
+    ///Returns what was expected in place of the error.
+    pub const fn expected(&self) -> Expected {
+        match self {
+            ParseError::InvalidLength(_) => Expected::Length,
+            ParseError::InvalidGroup(_) => Expected::Separator,
+            ParseError::InvalidGroupLen(group, _) => Expected::GroupLen(group_len(*group)),
+            //Hexadecimal digit can only be unexpected in place of separator
+            ParseError::InvalidByte(byte, _) => match byte.is_ascii_hexdigit() {
+                true => Expected::Separator,
+                false => Expected::HexDigit,
+            },
+        }
+    }
 }
 
 impl fmt::Display for ParseError {
@@ -661,13 +3260,64 @@ impl fmt::Display for ParseError {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ParseError::InvalidLength(len) => fmt.write_fmt(format_args!("Invalid length {}", len)),
-            ParseError::InvalidGroup(idx) => fmt.write_fmt(format_args!("Group {} has unexpected length", idx)),
+            ParseError::InvalidGroup(idx) => fmt.write_fmt(format_args!("Group {} is not followed by separator", idx)),
             ParseError::InvalidGroupLen(idx, len) => fmt.write_fmt(format_args!("Group {} has unexpected length {}", idx, len)),
             ParseError::InvalidByte(byte, pos) => fmt.write_fmt(format_args!("Invalid character '{:x}' at position {}", byte, pos)),
         }
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+///Error happening when decoding uuid from numeric encoding (e.g. base32, base58, base64 or OID).
+pub enum DecodeError {
+    ///Input has invalid length.
+    InvalidLength(usize),
+    ///Character is not a digit of encoding alphabet.
+    ///
+    ///1. Character byte;
+    ///2. Position from 0;
+    InvalidDigit(u8, usize),
+    ///Encoded value does not fit 128 bits.
+    ///
+    ///1. Position of digit, at which overflow happened;
+    Overflow(usize),
+}
+
+impl DecodeError {
+    #[inline]
+    ///Returns position of offending character within input, if error is caused by particular character.
+    pub const fn position(&self) -> Option<usize> {
+        match self {
+            DecodeError::InvalidLength(_) => None,
+            DecodeError::InvalidDigit(_, pos) => Some(*pos),
+            DecodeError::Overflow(pos) => Some(*pos),
+        }
+    }
+}
+
+impl fmt::Display for DecodeError {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidLength(len) => fmt.write_fmt(format_args!("Invalid length {}", len)),
+            DecodeError::InvalidDigit(byte, pos) => fmt.write_fmt(format_args!("Invalid digit '{:x}' at position {}", byte, pos)),
+            DecodeError::Overflow(pos) => fmt.write_fmt(format_args!("Value overflows 128 bits at position {}", pos)),
+        }
+    }
+}
+
+#[doc(hidden)]
+//Parses UUID within `uuid!`, failing compilation on invalid input.
+pub const fn __parse_uuid_or_panic(input: &str) -> Uuid {
+    match Uuid::parse_str(input) {
+        Ok(result) => result,
+        Err(ParseError::InvalidLength(_)) => panic!("Invalid UUID: unexpected length"),
+        Err(ParseError::InvalidGroup(_)) => panic!("Invalid UUID: group is not followed by separator"),
+        Err(ParseError::InvalidGroupLen(_, _)) => panic!("Invalid UUID: group has unexpected length"),
+        Err(ParseError::InvalidByte(_, _)) => panic!("Invalid UUID: invalid character"),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::byte_to_hex;
@@ -0,0 +1,46 @@
+use log::kv::{ToValue, Value};
+
+use crate::Uuid;
+
+impl ToValue for Uuid {
+    #[inline(always)]
+    fn to_value(&self) -> Value<'_> {
+        Value::from_display(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Uuid;
+
+    use core::fmt::{self, Write};
+    use log::kv::ToValue;
+
+    struct Buffer {
+        data: [u8; 36],
+        len: usize,
+    }
+
+    impl Write for Buffer {
+        fn write_str(&mut self, text: &str) -> fmt::Result {
+            let end = self.len + text.len();
+            match self.data.get_mut(self.len..end) {
+                Some(dest) => dest.copy_from_slice(text.as_bytes()),
+                None => return Err(fmt::Error),
+            }
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn should_convert_to_display_value() {
+        let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+        let mut buffer = Buffer {
+            data: [0; 36],
+            len: 0,
+        };
+        write!(buffer, "{}", uuid.to_value()).unwrap();
+        assert_eq!(&buffer.data[..buffer.len], b"60ecb7b6-ba34-5aad-a9ef-9020b1ea210a");
+    }
+}
@@ -0,0 +1,143 @@
+#[macro_export]
+///Defines newtype wrapper over [Uuid](struct.Uuid.html), to distinguish identifiers of different entities.
+///
+///Generated type implements `Display`, `Debug`, `FromStr`, `Default` and conversions from/to `Uuid`.
+///When `serde` feature is enabled, it is serialized the same way as `Uuid`.
+///
+///## Usage
+///
+///```rust
+///lolid::define_uuid_type!(
+///    ///User identifier.
+///    pub UserId
+///);
+///
+///let id = UserId::new(lolid::Uuid::nil());
+///assert_eq!(id.to_string(), "00000000-0000-0000-0000-000000000000");
+///assert_eq!("00000000-0000-0000-0000-000000000000".parse::<UserId>().unwrap(), id);
+///```
+macro_rules! define_uuid_type {
+    ($(#[$meta:meta])* $vis:vis $name:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+        #[repr(transparent)]
+        $vis struct $name($crate::Uuid);
+
+        impl $name {
+            #[inline(always)]
+            ///Creates new identifier from `Uuid`.
+            pub const fn new(uuid: $crate::Uuid) -> Self {
+                Self(uuid)
+            }
+
+            #[inline(always)]
+            ///Returns underlying `Uuid`.
+            pub const fn as_uuid(&self) -> &$crate::Uuid {
+                &self.0
+            }
+
+            #[inline(always)]
+            ///Returns underlying `Uuid`.
+            pub const fn into_uuid(self) -> $crate::Uuid {
+                self.0
+            }
+        }
+
+        impl core::fmt::Debug for $name {
+            #[inline(always)]
+            fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                fmt.write_fmt(format_args!("{}({})", stringify!($name), self.0))
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            #[inline(always)]
+            fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.0, fmt)
+            }
+        }
+
+        impl core::str::FromStr for $name {
+            type Err = $crate::ParseError;
+
+            #[inline(always)]
+            fn from_str(input: &str) -> Result<Self, Self::Err> {
+                $crate::Uuid::parse_str(input).map(Self)
+            }
+        }
+
+        impl From<$crate::Uuid> for $name {
+            #[inline(always)]
+            fn from(uuid: $crate::Uuid) -> Self {
+                Self(uuid)
+            }
+        }
+
+        impl From<$name> for $crate::Uuid {
+            #[inline(always)]
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl AsRef<$crate::Uuid> for $name {
+            #[inline(always)]
+            fn as_ref(&self) -> &$crate::Uuid {
+                &self.0
+            }
+        }
+
+        $crate::__define_uuid_type_serde!($name);
+    };
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_uuid_type_serde {
+    ($name:ident) => {
+        impl $crate::__serde::Serialize for $name {
+            #[inline(always)]
+            fn serialize<SER: $crate::__serde::Serializer>(&self, ser: SER) -> Result<SER::Ok, SER::Error> {
+                $crate::__serde::Serialize::serialize(&self.0, ser)
+            }
+        }
+
+        impl<'de> $crate::__serde::Deserialize<'de> for $name {
+            #[inline(always)]
+            fn deserialize<D: $crate::__serde::Deserializer<'de>>(des: D) -> Result<Self, D::Error> {
+                <$crate::Uuid as $crate::__serde::Deserialize<'de>>::deserialize(des).map(Self)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_uuid_type_serde {
+    ($name:ident) => {
+    };
+}
+
+#[macro_export]
+///Creates [Uuid](struct.Uuid.html) from string literal at compile time.
+///
+///Accepts the same forms as `Uuid::parse_str`, and fails compilation if input is invalid.
+///
+///## Usage
+///
+///```rust
+///const DNS: lolid::Uuid = lolid::uuid!("6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+///assert_eq!(DNS, lolid::NAMESPACE_DNS);
+///```
+///
+///```rust,compile_fail
+///let _ = lolid::uuid!("6ba7b810-9dad-11d1-80b4-00c04fd430cg");
+///```
+macro_rules! uuid {
+    ($text:expr) => {{
+        const UUID: $crate::Uuid = $crate::__parse_uuid_or_panic($text);
+        UUID
+    }};
+}
@@ -0,0 +1,33 @@
+//!Bulk rewriting of stored UUIDs.
+
+use crate::Uuid;
+
+///Rewrites each UUID in `uuids` with result of `visitor`, leaving UUID as it is if `None` is returned.
+///
+///Returns number of rewritten UUIDs.
+pub fn rewrite_in_place<F: FnMut(Uuid) -> Option<Uuid>>(uuids: &mut [Uuid], mut visitor: F) -> usize {
+    let mut result = 0;
+    for uuid in uuids.iter_mut() {
+        if let Some(new) = visitor(*uuid) {
+            *uuid = new;
+            result += 1;
+        }
+    }
+    result
+}
+
+#[inline]
+///Converts every `v1` UUID in `uuids` into `v6`, leaving other versions as they are.
+///
+///Returns number of converted UUIDs.
+pub fn v1_to_v6_in_place(uuids: &mut [Uuid]) -> usize {
+    rewrite_in_place(uuids, Uuid::v1_to_v6)
+}
+
+#[inline]
+///Converts every `v6` UUID in `uuids` into `v1`, leaving other versions as they are.
+///
+///Returns number of converted UUIDs.
+pub fn v6_to_v1_in_place(uuids: &mut [Uuid]) -> usize {
+    rewrite_in_place(uuids, Uuid::v6_to_v1)
+}
@@ -0,0 +1,72 @@
+use core::convert::TryFrom;
+
+use mysql_common::value::Value;
+use mysql_common::value::convert::{FromValue, FromValueError};
+
+use crate::{UUID_SIZE, Uuid};
+
+impl From<Uuid> for Value {
+    #[inline(always)]
+    fn from(uuid: Uuid) -> Self {
+        Value::Bytes(uuid.as_bytes().to_vec())
+    }
+}
+
+impl TryFrom<Value> for Uuid {
+    type Error = FromValueError;
+
+    #[inline]
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            //BINARY(16)
+            Value::Bytes(ref bytes) if bytes.len() == UUID_SIZE => match Uuid::from_slice(bytes) {
+                Some(uuid) => Ok(uuid),
+                None => Err(FromValueError(value)),
+            },
+            //CHAR(36) or CHAR(32)
+            Value::Bytes(ref bytes) => match Uuid::parse_ascii_bytes(bytes) {
+                Ok(uuid) => Ok(uuid),
+                Err(_) => Err(FromValueError(value)),
+            },
+            value => Err(FromValueError(value)),
+        }
+    }
+}
+
+impl FromValue for Uuid {
+    type Intermediate = Uuid;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Uuid;
+
+    use mysql_common::value::Value;
+    use mysql_common::value::convert::FromValue;
+
+    #[test]
+    fn should_convert_to_binary_value() {
+        let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+        let value = Value::from(uuid);
+        assert_eq!(value, Value::Bytes(uuid.as_bytes().to_vec()));
+        assert_eq!(Uuid::from_value_opt(value).unwrap(), uuid);
+    }
+
+    #[test]
+    fn should_convert_from_text_value() {
+        let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+        let value = Value::Bytes(b"60ecb7b6-ba34-5aad-a9ef-9020b1ea210a".to_vec());
+        assert_eq!(Uuid::from_value_opt(value).unwrap(), uuid);
+        let value = Value::Bytes(b"60ecb7b6ba345aada9ef9020b1ea210a".to_vec());
+        assert_eq!(Uuid::from_value_opt(value).unwrap(), uuid);
+    }
+
+    #[test]
+    fn should_fail_convert_invalid_value() {
+        let value = Value::Bytes(b"60ecb7b6".to_vec());
+        assert_eq!(Uuid::from_value_opt(value.clone()).unwrap_err().0, value);
+        let value = Value::Int(1);
+        assert_eq!(Uuid::from_value_opt(value.clone()).unwrap_err().0, value);
+        Uuid::from_value_opt(Value::NULL).unwrap_err();
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{Uuid, Version};
+
+///Incremental hasher for name based UUIDs, allowing to compose name out of multiple slices.
+///
+///Name is fed to every hasher enabled via features (`md5`, `sha1` and `sha256`).
+///
+///```rust
+///# #[cfg(feature = "sha1")] {
+///use lolid::{NameHasher, Uuid, NAMESPACE_DNS};
+///
+///const UUID: Uuid = NameHasher::new(NAMESPACE_DNS).update(b"rust-lang").update(b".org").finish_v5();
+///assert_eq!(UUID, Uuid::v5(NAMESPACE_DNS, b"rust-lang.org"));
+///# }
+///```
+pub struct NameHasher {
+    #[cfg(feature = "md5")]
+    md5: lhash::Md5,
+    #[cfg(feature = "sha1")]
+    sha1: lhash::Sha1,
+    #[cfg(feature = "sha256")]
+    sha256: lhash::Sha256,
+}
+
+impl NameHasher {
+    #[inline]
+    ///Creates new hasher within `namespace`.
+    pub const fn new(namespace: Uuid) -> Self {
+        Self {
+            #[cfg(feature = "md5")]
+            md5: lhash::Md5::new(),
+            #[cfg(feature = "sha1")]
+            sha1: lhash::Sha1::new(),
+            #[cfg(feature = "sha256")]
+            sha256: lhash::Sha256::new(),
+        }.update(&namespace.data)
+    }
+
+    #[inline]
+    ///Appends `name` part to the hashed name.
+    pub const fn update(self, name: &[u8]) -> Self {
+        Self {
+            #[cfg(feature = "md5")]
+            md5: self.md5.const_update(name),
+            #[cfg(feature = "sha1")]
+            sha1: self.sha1.const_update(name),
+            #[cfg(feature = "sha256")]
+            sha256: self.sha256.const_update(name),
+        }
+    }
+
+    #[cfg(feature = "md5")]
+    ///Generates UUID `v3`, same as `Uuid::v3` with concatenated name.
+    ///
+    ///Only available when `md5` feature is enabled.
+    pub const fn finish_v3(&self) -> Uuid {
+        let hash = self.md5.const_result();
+
+        Uuid::from_bytes([
+            hash[0], hash[1], hash[2], hash[3], hash[4], hash[5], hash[6], hash[7],
+            hash[8], hash[9], hash[10], hash[11], hash[12], hash[13], hash[14], hash[15],
+        ]).set_variant().set_version(Version::Md5)
+    }
+
+    #[cfg(feature = "sha1")]
+    ///Generates UUID `v5`, same as `Uuid::v5` with concatenated name.
+    ///
+    ///Only available when `sha1` feature is enabled.
+    pub const fn finish_v5(&self) -> Uuid {
+        let hash = self.sha1.const_result();
+
+        Uuid::from_bytes([
+            hash[0], hash[1], hash[2], hash[3], hash[4], hash[5], hash[6], hash[7],
+            hash[8], hash[9], hash[10], hash[11], hash[12], hash[13], hash[14], hash[15],
+        ]).set_variant().set_version(Version::Sha1)
+    }
+
+    #[cfg(feature = "sha256")]
+    ///Generates UUID `v8`, same as `Uuid::v8_sha256` with concatenated name.
+    ///
+    ///Only available when `sha256` feature is enabled.
+    pub const fn finish_v8_sha256(&self) -> Uuid {
+        let hash = self.sha256.const_result();
+
+        Uuid::from_bytes([
+            hash[0], hash[1], hash[2], hash[3], hash[4], hash[5], hash[6], hash[7],
+            hash[8], hash[9], hash[10], hash[11], hash[12], hash[13], hash[14], hash[15],
+        ]).set_variant().set_version(Version::Custom)
+    }
+}
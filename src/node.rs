@@ -0,0 +1,210 @@
+use core::fmt;
+
+use core::mem;
+
+use crate::{byte_to_hex, hex_to_byte, fnv1a, FNV_OFFSET_BASIS, ParseError, TextRepr};
+
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicU64, Ordering};
+
+//Upper bit is used to indicate that node is set.
+#[cfg(target_has_atomic = "64")]
+const IS_SET: u64 = 1 << 63;
+
+#[cfg(target_has_atomic = "64")]
+static NODE_ID: AtomicU64 = AtomicU64::new(0);
+
+#[cfg(target_has_atomic = "64")]
+///Sets process-wide node id, used by convenience constructors (e.g. `Uuid::v1_now` and `Uuid::v6_now`).
+///
+///Node can be set only once, returning `false` if it was already set.
+pub fn set_node_id(node: [u8; 6]) -> bool {
+    let value = IS_SET | u64::from_be_bytes([0, 0, node[0], node[1], node[2], node[3], node[4], node[5]]);
+    NODE_ID.compare_exchange(0, value, Ordering::AcqRel, Ordering::Acquire).is_ok()
+}
+
+#[cfg(target_has_atomic = "64")]
+///Returns process-wide node id, if it was set via `set_node_id`.
+pub fn node_id() -> Option<[u8; 6]> {
+    let value = NODE_ID.load(Ordering::Acquire);
+    match value & IS_SET {
+        0 => None,
+        _ => {
+            let value = value.to_be_bytes();
+            Some([value[2], value[3], value[4], value[5], value[6], value[7]])
+        }
+    }
+}
+
+///Derives stable node id from arbitrary name (e.g. hostname), using FNV-1a hash.
+///
+///As node is not real MAC address, multicast bit is set, as required by RFC4122.
+pub const fn node_id_from_name(name: &[u8]) -> [u8; 6] {
+    let hash = fnv1a(FNV_OFFSET_BASIS, name).to_be_bytes();
+    [hash[2] | 0x01, hash[3], hash[4], hash[5], hash[6], hash[7]]
+}
+
+///Derives node id from interface identifier of IPv6 address (e.g. result of `Ipv6Addr::octets`).
+///
+///If interface identifier is modified EUI-64, then original MAC address is recovered.
+///Otherwise lower 48 bits of identifier are used with multicast bit set, as they do not
+///represent real MAC address.
+pub const fn node_id_from_ipv6(addr: [u8; 16]) -> [u8; 6] {
+    if addr[11] == 0xff && addr[12] == 0xfe {
+        //Universal/local bit is inverted in modified EUI-64
+        [addr[8] ^ 0x02, addr[9], addr[10], addr[13], addr[14], addr[15]]
+    } else {
+        [addr[10] | 0x01, addr[11], addr[12], addr[13], addr[14], addr[15]]
+    }
+}
+
+#[cfg(feature = "std")]
+///Derives stable node id from hostname of the current machine.
+///
+///Hostname is looked up in `/proc/sys/kernel/hostname`, `/etc/hostname`, and then in
+///`HOSTNAME` and `COMPUTERNAME` environment variables, returning `None` if none are available.
+///
+///Only available when `std` feature is enabled.
+pub fn hostname_node_id() -> Option<[u8; 6]> {
+    use std::string::String;
+
+    fn from_text(hostname: String) -> Option<[u8; 6]> {
+        match hostname.trim() {
+            "" => None,
+            hostname => Some(node_id_from_name(hostname.as_bytes())),
+        }
+    }
+
+    for path in ["/proc/sys/kernel/hostname", "/etc/hostname"].iter() {
+        if let Some(node) = std::fs::read_to_string(path).ok().and_then(from_text) {
+            return Some(node);
+        }
+    }
+
+    for var in ["HOSTNAME", "COMPUTERNAME"].iter() {
+        if let Some(node) = std::env::var(var).ok().and_then(from_text) {
+            return Some(node);
+        }
+    }
+
+    None
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+///MAC address, used as node of time based UUID.
+pub struct MacAddress(pub [u8; 6]);
+
+impl MacAddress {
+    #[inline(always)]
+    ///Creates new instance from raw bytes.
+    pub const fn new(bytes: [u8; 6]) -> Self {
+        Self(bytes)
+    }
+
+    #[inline(always)]
+    ///Returns raw bytes.
+    pub const fn bytes(&self) -> [u8; 6] {
+        self.0
+    }
+
+    #[inline]
+    ///Creates textual representation `aa:bb:cc:dd:ee:ff` in a static buffer.
+    pub const fn to_str(&self) -> TextRepr<17> {
+        const SEP: u8 = b':';
+
+        let mac = &self.0;
+        let storage = [
+            mem::MaybeUninit::new(byte_to_hex(mac[0], 1)),
+            mem::MaybeUninit::new(byte_to_hex(mac[0], 0)),
+            mem::MaybeUninit::new(SEP),
+            mem::MaybeUninit::new(byte_to_hex(mac[1], 1)),
+            mem::MaybeUninit::new(byte_to_hex(mac[1], 0)),
+            mem::MaybeUninit::new(SEP),
+            mem::MaybeUninit::new(byte_to_hex(mac[2], 1)),
+            mem::MaybeUninit::new(byte_to_hex(mac[2], 0)),
+            mem::MaybeUninit::new(SEP),
+            mem::MaybeUninit::new(byte_to_hex(mac[3], 1)),
+            mem::MaybeUninit::new(byte_to_hex(mac[3], 0)),
+            mem::MaybeUninit::new(SEP),
+            mem::MaybeUninit::new(byte_to_hex(mac[4], 1)),
+            mem::MaybeUninit::new(byte_to_hex(mac[4], 0)),
+            mem::MaybeUninit::new(SEP),
+            mem::MaybeUninit::new(byte_to_hex(mac[5], 1)),
+            mem::MaybeUninit::new(byte_to_hex(mac[5], 0)),
+        ];
+
+        unsafe {
+            TextRepr(str_buf::StrBuf::from_storage(storage, 17))
+        }
+    }
+
+    ///Creates new instance by parsing `aa:bb:cc:dd:ee:ff` or `aa-bb-cc-dd-ee-ff` text.
+    ///
+    ///Separator must be the same across whole input.
+    pub const fn parse_ascii_bytes(input: &[u8]) -> Result<Self, ParseError> {
+        const LEN: usize = 17;
+
+        if input.len() != LEN {
+            return Err(ParseError::InvalidLength(input.len()));
+        }
+
+        let sep = match input[2] {
+            sep @ (b':' | b'-') => sep,
+            chr => return Err(ParseError::InvalidByte(chr, 2)),
+        };
+
+        let mut result = [0; 6];
+        let mut idx = 0;
+        while idx < result.len() {
+            let cursor = idx * 3;
+            if idx > 0 && input[cursor - 1] != sep {
+                return Err(ParseError::InvalidByte(input[cursor - 1], cursor - 1));
+            }
+
+            result[idx] = match hex_to_byte(input, cursor) {
+                Ok(byte) => byte,
+                Err(error) => return Err(error),
+            };
+            idx += 1;
+        }
+
+        Ok(Self(result))
+    }
+
+    #[inline(always)]
+    ///Creates new instance by parsing `aa:bb:cc:dd:ee:ff` or `aa-bb-cc-dd-ee-ff` text.
+    pub const fn parse_str(input: &str) -> Result<Self, ParseError> {
+        Self::parse_ascii_bytes(input.as_bytes())
+    }
+}
+
+impl From<[u8; 6]> for MacAddress {
+    #[inline(always)]
+    fn from(bytes: [u8; 6]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<MacAddress> for [u8; 6] {
+    #[inline(always)]
+    fn from(mac: MacAddress) -> Self {
+        mac.0
+    }
+}
+
+impl core::str::FromStr for MacAddress {
+    type Err = ParseError;
+
+    #[inline(always)]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse_ascii_bytes(input.as_bytes())
+    }
+}
+
+impl fmt::Display for MacAddress {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(self.to_str().as_str())
+    }
+}
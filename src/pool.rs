@@ -0,0 +1,281 @@
+use core::cell::UnsafeCell;
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::boxed::Box;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+
+use crate::Uuid;
+
+struct Slot {
+    //Position at which slot can be written (equal) or read (greater by one).
+    sequence: AtomicUsize,
+    value: UnsafeCell<Uuid>,
+}
+
+//Bounded lock-free queue of fixed size, with single producer and multiple consumers.
+//
+//Each slot carries its own sequence number, so that consumers only contend on `head` and
+//never wait for each other, as long as there are values in queue.
+struct Ring {
+    slots: Box<[Slot]>,
+    mask: usize,
+    head: AtomicUsize,
+    tail: AtomicUsize,
+}
+
+//Access to `value` of slot is guarded by its `sequence`.
+unsafe impl Sync for Ring {}
+
+impl Ring {
+    fn new(capacity: usize) -> Self {
+        let capacity = capacity.next_power_of_two();
+        Self {
+            slots: (0..capacity).map(|idx| Slot {
+                sequence: AtomicUsize::new(idx),
+                value: UnsafeCell::new(Uuid::nil()),
+            }).collect(),
+            mask: capacity - 1,
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+        }
+    }
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.tail.load(Ordering::Acquire).wrapping_sub(self.head.load(Ordering::Acquire))
+    }
+
+    //Must only be called by single producer.
+    fn push(&self, value: Uuid) -> bool {
+        let pos = self.tail.load(Ordering::Relaxed);
+        let slot = &self.slots[pos & self.mask];
+        if slot.sequence.load(Ordering::Acquire) != pos {
+            return false;
+        }
+
+        unsafe {
+            *slot.value.get() = value;
+        }
+        slot.sequence.store(pos.wrapping_add(1), Ordering::Release);
+        self.tail.store(pos.wrapping_add(1), Ordering::Release);
+        true
+    }
+
+    //Returns value with number of values remaining after it.
+    fn pop(&self) -> Option<(Uuid, usize)> {
+        let mut pos = self.head.load(Ordering::Relaxed);
+        loop {
+            let slot = &self.slots[pos & self.mask];
+            let sequence = slot.sequence.load(Ordering::Acquire);
+            let diff = sequence.wrapping_sub(pos.wrapping_add(1)) as isize;
+
+            if diff == 0 {
+                match self.head.compare_exchange_weak(pos, pos.wrapping_add(1), Ordering::AcqRel, Ordering::Relaxed) {
+                    Ok(_) => {
+                        let value = unsafe {
+                            *slot.value.get()
+                        };
+                        slot.sequence.store(pos.wrapping_add(self.capacity()), Ordering::Release);
+                        let remaining = self.tail.load(Ordering::Acquire).wrapping_sub(pos.wrapping_add(1));
+                        return Some((value, remaining));
+                    },
+                    Err(actual) => pos = actual,
+                }
+            } else if diff < 0 {
+                //Slot is not written yet, hence queue is empty.
+                return None;
+            } else {
+                //Slot is already taken by another consumer.
+                pos = self.head.load(Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+struct Shared {
+    ring: Ring,
+    //Only used to put background thread to sleep, while pool is filled.
+    lock: Mutex<()>,
+    refill: Condvar,
+    generator: fn() -> Uuid,
+    is_stopped: AtomicBool,
+    #[cfg(feature = "async")]
+    waiters: Mutex<std::vec::Vec<core::task::Waker>>,
+}
+
+impl Shared {
+    #[inline(always)]
+    fn low_watermark(&self) -> usize {
+        self.ring.capacity() / 2
+    }
+
+    fn notify_refill(&self) {
+        //Notify under lock, so that it cannot slip in between check of length and wait.
+        let _lock = self.lock.lock().unwrap_or_else(|error| error.into_inner());
+        self.refill.notify_one();
+    }
+
+    fn pop(&self) -> Option<Uuid> {
+        match self.ring.pop() {
+            Some((result, remaining)) => {
+                //Positions of consumers are unique, hence only one of them hits watermark.
+                if remaining == self.low_watermark() {
+                    self.notify_refill();
+                }
+                Some(result)
+            },
+            None => {
+                self.notify_refill();
+                None
+            }
+        }
+    }
+
+    fn run(&self) {
+        loop {
+            let mut lock = self.lock.lock().unwrap_or_else(|error| error.into_inner());
+            while self.ring.len() > self.low_watermark() && !self.is_stopped.load(Ordering::Acquire) {
+                lock = self.refill.wait(lock).unwrap_or_else(|error| error.into_inner());
+            }
+            drop(lock);
+            if self.is_stopped.load(Ordering::Acquire) {
+                break;
+            }
+
+            //Consumers only take values out, so there is always enough space for missing ones.
+            let missing = self.ring.capacity() - self.ring.len();
+            for _ in 0..missing {
+                self.ring.push((self.generator)());
+            }
+
+            #[cfg(feature = "async")]
+            {
+                let mut waiters = self.waiters.lock().unwrap_or_else(|error| error.into_inner());
+                for waiter in waiters.drain(..) {
+                    waiter.wake();
+                }
+            }
+        }
+    }
+}
+
+///Pool of UUIDs, pre-generated on background thread.
+///
+///Only available when `std` feature is enabled.
+///
+///UUIDs are stored in lock-free queue, so taking UUID out of the pool never blocks on other consumers.
+///Background thread keeps pool filled up to its capacity, refilling it once half of it is consumed.
+///If pool is exhausted, UUID is generated in place, instead of waiting for background thread.
+///
+///Background thread is stopped when pool is dropped.
+pub struct UuidPool {
+    shared: Arc<Shared>,
+    worker: Option<thread::JoinHandle<()>>,
+}
+
+impl UuidPool {
+    ///Creates new pool with specified `capacity`, using `generator` to create UUIDs (e.g. `Uuid::v4`).
+    ///
+    ///Capacity is rounded up to power of two.
+    ///
+    ///## Panics
+    ///
+    ///If `capacity` is zero or background thread cannot be spawned.
+    pub fn new(capacity: usize, generator: fn() -> Uuid) -> Self {
+        assert_ne!(capacity, 0, "Pool capacity cannot be zero");
+
+        let shared = Arc::new(Shared {
+            ring: Ring::new(capacity),
+            lock: Mutex::new(()),
+            refill: Condvar::new(),
+            generator,
+            is_stopped: AtomicBool::new(false),
+            #[cfg(feature = "async")]
+            waiters: Mutex::new(std::vec::Vec::new()),
+        });
+
+        let worker = {
+            let shared = shared.clone();
+            thread::Builder::new().name("lolid-pool".into())
+                                  .spawn(move || shared.run())
+                                  .expect("To spawn pool thread")
+        };
+
+        Self {
+            shared,
+            worker: Some(worker),
+        }
+    }
+
+    #[inline(always)]
+    ///Returns capacity of the pool.
+    pub fn capacity(&self) -> usize {
+        self.shared.ring.capacity()
+    }
+
+    #[inline]
+    ///Returns pre-generated UUID, or generates new one if pool is exhausted.
+    pub fn next(&self) -> Uuid {
+        match self.shared.pop() {
+            Some(result) => result,
+            None => (self.shared.generator)(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    ///Returns pre-generated UUID, waiting for background thread to refill pool if it is exhausted.
+    ///
+    ///Only available when `async` feature is enabled.
+    ///
+    ///Unlike `next`, never generates UUID in place, so calling task is never blocked on entropy acquisition.
+    ///Executor agnostic, as background thread wakes up task once pool is refilled.
+    pub async fn next_async(&self) -> Uuid {
+        Next {
+            shared: &self.shared,
+        }.await
+    }
+}
+
+#[cfg(feature = "async")]
+struct Next<'a> {
+    shared: &'a Shared,
+}
+
+#[cfg(feature = "async")]
+impl core::future::Future for Next<'_> {
+    type Output = Uuid;
+
+    fn poll(self: core::pin::Pin<&mut Self>, ctx: &mut core::task::Context<'_>) -> core::task::Poll<Self::Output> {
+        if let Some(result) = self.shared.pop() {
+            return core::task::Poll::Ready(result);
+        }
+
+        {
+            let mut waiters = self.shared.waiters.lock().unwrap_or_else(|error| error.into_inner());
+            if !waiters.iter().any(|waiter| waiter.will_wake(ctx.waker())) {
+                waiters.push(ctx.waker().clone());
+            }
+        }
+
+        //Check again after registration, as refill could complete before it.
+        match self.shared.ring.pop() {
+            Some((result, _)) => core::task::Poll::Ready(result),
+            None => core::task::Poll::Pending,
+        }
+    }
+}
+
+impl Drop for UuidPool {
+    fn drop(&mut self) {
+        self.shared.is_stopped.store(true, Ordering::Release);
+        self.shared.notify_refill();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
@@ -183,6 +183,18 @@ mod tests {
         assert_eq!(res, uuid);
     }
 
+    #[test]
+    fn define_uuid_type_bincode() {
+        crate::define_uuid_type!(Id);
+
+        let id = Id::new(Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap());
+        let serialized = bincode::serialize(&id).unwrap();
+        assert_eq!(serialized, bincode::serialize(id.as_uuid()).unwrap());
+
+        let res: Id = bincode::deserialize(&serialized).expect("Unexpected fail");
+        assert_eq!(res.into_uuid(), *id.as_uuid());
+    }
+
     #[test]
     fn deserialize_bincode_invalid_len() {
         let bytes = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
@@ -0,0 +1,55 @@
+use slog::{Key, Record, Serializer, Value};
+
+use crate::Uuid;
+
+impl Value for Uuid {
+    #[inline]
+    fn serialize(&self, _: &Record, key: Key, serializer: &mut dyn Serializer) -> slog::Result {
+        serializer.emit_str(key, self.to_str().as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Uuid;
+
+    use core::fmt;
+    use core::sync::atomic::{AtomicBool, Ordering};
+
+    static IS_LOGGED: AtomicBool = AtomicBool::new(false);
+
+    struct Check;
+
+    impl slog::Serializer for Check {
+        fn emit_arguments(&mut self, _: slog::Key, _: &fmt::Arguments) -> slog::Result {
+            unreachable!();
+        }
+
+        fn emit_str(&mut self, key: slog::Key, value: &str) -> slog::Result {
+            assert_eq!(key, "id");
+            assert_eq!(value, "60ecb7b6-ba34-5aad-a9ef-9020b1ea210a");
+            IS_LOGGED.store(true, Ordering::Release);
+            Ok(())
+        }
+    }
+
+    struct Drain;
+
+    impl slog::Drain for Drain {
+        type Ok = ();
+        type Err = slog::Never;
+
+        fn log(&self, record: &slog::Record, _: &slog::OwnedKVList) -> Result<Self::Ok, Self::Err> {
+            slog::KV::serialize(&record.kv(), record, &mut Check).expect("To serialize");
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn should_log_uuid_as_str() {
+        let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+        let log = slog::Logger::root(Drain, slog::o!());
+        slog::info!(log, "uuid"; "id" => uuid);
+        assert!(IS_LOGGED.load(Ordering::Acquire));
+    }
+}
@@ -0,0 +1,90 @@
+//TDS sends `uniqueidentifier` with first three fields in little endian.
+//`tiberius` swaps them itself, storing `tiberius::Uuid` in the same RFC4122 order as `Uuid`,
+//so bytes are passed as they are to avoid double swap.
+
+use tiberius::{ColumnData, FromSql, FromSqlOwned, IntoSql, ToSql};
+
+use crate::Uuid;
+
+impl From<Uuid> for tiberius::Uuid {
+    #[inline(always)]
+    fn from(uuid: Uuid) -> Self {
+        tiberius::Uuid::from_bytes(uuid.bytes())
+    }
+}
+
+impl From<tiberius::Uuid> for Uuid {
+    #[inline(always)]
+    fn from(uuid: tiberius::Uuid) -> Self {
+        Uuid::from_bytes(*uuid.as_bytes())
+    }
+}
+
+impl ToSql for Uuid {
+    #[inline(always)]
+    fn to_sql(&self) -> ColumnData<'_> {
+        ColumnData::Guid(Some((*self).into()))
+    }
+}
+
+impl<'a> IntoSql<'a> for Uuid {
+    #[inline(always)]
+    fn into_sql(self) -> ColumnData<'a> {
+        ColumnData::Guid(Some(self.into()))
+    }
+}
+
+#[inline]
+fn from_column(data: &ColumnData<'static>) -> tiberius::Result<Option<Uuid>> {
+    match data {
+        ColumnData::Guid(uuid) => Ok(uuid.map(Uuid::from)),
+        _ => Err(tiberius::error::Error::Conversion("cannot interpret value as an Uuid".into())),
+    }
+}
+
+impl<'a> FromSql<'a> for Uuid {
+    #[inline(always)]
+    fn from_sql(data: &'a ColumnData<'static>) -> tiberius::Result<Option<Self>> {
+        from_column(data)
+    }
+}
+
+impl FromSqlOwned for Uuid {
+    #[inline(always)]
+    fn from_sql_owned(data: ColumnData<'static>) -> tiberius::Result<Option<Self>> {
+        from_column(&data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Uuid;
+
+    use tiberius::{ColumnData, FromSql, FromSqlOwned, IntoSql, ToSql};
+
+    #[test]
+    fn should_convert_to_guid() {
+        let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+        let expected = tiberius::Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+
+        match uuid.to_sql() {
+            ColumnData::Guid(Some(guid)) => assert_eq!(guid, expected),
+            _ => panic!("Unexpected column type"),
+        }
+        match uuid.into_sql() {
+            ColumnData::Guid(Some(guid)) => assert_eq!(guid, expected),
+            _ => panic!("Unexpected column type"),
+        }
+    }
+
+    #[test]
+    fn should_convert_from_guid() {
+        let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+        let data = uuid.into_sql();
+
+        assert_eq!(Uuid::from_sql(&data).unwrap(), Some(uuid));
+        assert_eq!(Uuid::from_sql_owned(data).unwrap(), Some(uuid));
+        assert_eq!(Uuid::from_sql(&ColumnData::Guid(None)).unwrap(), None);
+        Uuid::from_sql(&ColumnData::I32(Some(1))).unwrap_err();
+    }
+}
@@ -0,0 +1,56 @@
+use valuable::{Valuable, Value, Visit, Structable, StructDef, Fields};
+
+use crate::Uuid;
+
+impl Valuable for Uuid {
+    #[inline(always)]
+    fn as_value(&self) -> Value<'_> {
+        Value::Structable(self)
+    }
+
+    #[inline]
+    fn visit(&self, visit: &mut dyn Visit) {
+        visit.visit_unnamed_fields(&[Value::String(self.to_str().as_str())]);
+    }
+}
+
+impl Structable for Uuid {
+    #[inline(always)]
+    fn definition(&self) -> StructDef<'_> {
+        StructDef::new_static("Uuid", Fields::Unnamed(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Uuid;
+
+    use valuable::{Valuable, Value, Visit, Structable, Fields};
+
+    #[test]
+    fn should_visit_text() {
+        struct Text(bool);
+
+        impl Visit for Text {
+            fn visit_value(&mut self, _: Value<'_>) {
+                unreachable!();
+            }
+
+            fn visit_unnamed_fields(&mut self, values: &[Value<'_>]) {
+                match values {
+                    [Value::String(text)] => self.0 = *text == "60ecb7b6-ba34-5aad-a9ef-9020b1ea210a",
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+        let mut text = Text(false);
+        uuid.visit(&mut text);
+        assert!(text.0);
+
+        assert!(matches!(uuid.as_value(), Value::Structable(_)));
+        assert_eq!(uuid.definition().name(), "Uuid");
+        assert!(matches!(uuid.definition().fields(), Fields::Unnamed(1)));
+    }
+}
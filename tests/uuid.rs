@@ -227,3 +227,1702 @@ fn check_parse_str() {
     let err = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a-").unwrap_err();
     assert_eq!(err, lolid::ParseError::InvalidLength(37));
 }
+
+#[test]
+fn check_parse_str_invalid_group_len() {
+    let err = Uuid::parse_str("60ecb7b-6ba34-5aad-a9ef-9020b1ea210a").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidGroupLen(1, 7));
+
+    let err = Uuid::parse_str("60ecb7b6a-ba3-5aad-a9ef-9020b1ea210a").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidGroupLen(1, 9));
+
+    let err = Uuid::parse_str("60ecb7b6-ba345-aad-a9ef-9020b1ea210a").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidGroupLen(2, 5));
+
+    let err = Uuid::parse_str("60ecb7b6-ba34-5aa-da9ef-9020b1ea210a").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidGroupLen(3, 3));
+
+    let err = Uuid::parse_str("60ecb7b6-ba34-5aad-a9e-f9020b1ea210a").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidGroupLen(4, 3));
+
+    let err = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef9-020b1ea210a").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidGroupLen(4, 5));
+
+    let err = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1-a210a").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidGroupLen(5, 6));
+}
+
+#[test]
+fn check_parse_error_details() {
+    use lolid::{Expected, ParseErrorKind};
+
+    let err = Uuid::parse_str("60ecb7b6-ba34-5aa,-a9ef-9020b1ea210a").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::InvalidByte);
+    assert_eq!(err.position(), Some(17));
+    assert_eq!(err.expected(), Expected::HexDigit);
+
+    let err = Uuid::parse_str("60ecb7b6-ba34-5aadga9ef-9020b1ea210a").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::InvalidGroup);
+    assert_eq!(err.position(), Some(18));
+    assert_eq!(err.expected(), Expected::Separator);
+
+    let err = Uuid::parse_str("60ecb7b6-ba345-aad-a9ef-9020b1ea210a").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::InvalidGroupLen);
+    assert_eq!(err.position(), Some(14));
+    assert_eq!(err.expected(), Expected::GroupLen(4));
+
+    let err = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1-a210a").unwrap_err();
+    assert_eq!(err.position(), Some(30));
+    assert_eq!(err.expected(), Expected::GroupLen(12));
+
+    let err = Uuid::parse_str("60ecb7b6").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::InvalidLength);
+    assert_eq!(err.position(), None);
+    assert_eq!(err.expected(), Expected::Length);
+}
+
+#[test]
+fn check_version_text() {
+    use lolid::Version;
+
+    assert_eq!(Version::Nil.to_string(), "nil");
+    assert_eq!(Version::Mac.to_string(), "v1");
+    assert_eq!(Version::Random.to_string(), "v4");
+    assert_eq!(Version::Sha1.to_string(), "v5");
+
+    assert_eq!("nil".parse::<Version>().unwrap(), Version::Nil);
+    assert_eq!("v2".parse::<Version>().unwrap(), Version::Dce);
+    assert_eq!("V3".parse::<Version>().unwrap(), Version::Md5);
+    assert_eq!("v4".parse::<Version>().unwrap(), Version::Random);
+    "v".parse::<Version>().unwrap_err();
+    "v9".parse::<Version>().unwrap_err();
+    "4".parse::<Version>().unwrap_err();
+}
+
+#[test]
+fn check_version_numeric() {
+    use core::convert::TryFrom;
+    use lolid::{InvalidVersion, Version};
+
+    for num in 0..=8 {
+        let version = Version::try_from(num).unwrap();
+        assert_eq!(version.as_u8(), Some(num));
+    }
+    assert_eq!(Version::Unknown.as_u8(), None);
+
+    assert_eq!(Version::try_from(1), Ok(Version::Mac));
+    assert_eq!(Version::try_from(6), Ok(Version::SortMac));
+    assert_eq!(Version::try_from(7), Ok(Version::SortRand));
+    assert_eq!(Version::try_from(8), Ok(Version::Custom));
+    assert_eq!(Version::try_from(9), Err(InvalidVersion));
+    assert_eq!(Version::try_from(255), Err(InvalidVersion));
+}
+
+#[test]
+fn check_alternate_debug() {
+    let uuid = Uuid::parse_str("20616934-4ba2-11e7-8001-010203040506").unwrap();
+    assert_eq!(format!("{:?}", uuid), "20616934-4ba2-11e7-8001-010203040506");
+
+    let debug = format!("{:#?}", uuid);
+    assert!(debug.contains("text: \"20616934-4ba2-11e7-8001-010203040506\""));
+    assert!(debug.contains("version: Some(\n        Mac,\n    )"));
+    assert!(debug.contains("variant: Rfc4122"));
+    assert!(debug.contains("time_low: 0x20616934"));
+    assert!(debug.contains("time_mid: 0x4ba2"));
+    assert!(debug.contains("time_high_and_version: 0x11e7"));
+    assert!(debug.contains("clock_seq: 0x8001"));
+    assert!(debug.contains("node: 010203040506"));
+
+    let debug = format!("{:#?}", Uuid::from_bytes([0xff; 16]));
+    assert!(debug.contains("version: None"));
+    assert!(debug.contains("variant: Future"));
+}
+
+#[test]
+fn check_node_id() {
+    const NODE: [u8; 6] = [0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f];
+
+    assert!(lolid::set_node_id(NODE));
+    assert!(!lolid::set_node_id([1, 2, 3, 4, 5, 6]));
+    assert_eq!(lolid::node_id(), Some(NODE));
+
+    #[cfg(feature = "std")]
+    {
+        let uuid = Uuid::v1_now().unwrap();
+        assert!(uuid.is_version(lolid::Version::Mac));
+        assert!(uuid.is_variant());
+        assert_eq!(uuid.node(), NODE);
+
+        let uuid = Uuid::v6_now().unwrap();
+        assert!(uuid.is_version(lolid::Version::SortMac));
+        assert!(uuid.is_variant());
+        assert_eq!(uuid.node(), NODE);
+        assert!(Uuid::v6_now().unwrap() > uuid);
+    }
+}
+
+#[test]
+fn check_node_id_from_name() {
+    let node = lolid::node_id_from_name(b"pod-1");
+    assert_eq!(node[0] & 0x01, 0x01);
+    assert_eq!(node, lolid::node_id_from_name(b"pod-1"));
+    assert_ne!(node, lolid::node_id_from_name(b"pod-2"));
+
+    #[cfg(feature = "std")]
+    {
+        if let Some(node) = lolid::hostname_node_id() {
+            assert_eq!(node[0] & 0x01, 0x01);
+            assert_eq!(Some(node), lolid::hostname_node_id());
+        }
+    }
+}
+
+#[test]
+fn check_node_id_from_ipv6() {
+    //fe80::211:22ff:fe33:4455 is derived from 00:11:22:33:44:55
+    let addr = [0xfe, 0x80, 0, 0, 0, 0, 0, 0, 0x02, 0x11, 0x22, 0xff, 0xfe, 0x33, 0x44, 0x55];
+    assert_eq!(lolid::node_id_from_ipv6(addr), [0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+
+    let addr = [0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0x1c, 0x2b, 0x3a, 0x49, 0x58, 0x67, 0x76, 0x84];
+    assert_eq!(lolid::node_id_from_ipv6(addr), [0x3b, 0x49, 0x58, 0x67, 0x76, 0x84]);
+}
+
+#[test]
+fn check_mac_address() {
+    use lolid::{MacAddress, ParseError};
+
+    let mac: MacAddress = "01:02:03:0a:0B:ff".parse().unwrap();
+    assert_eq!(mac, MacAddress([1, 2, 3, 0x0a, 0x0b, 0xff]));
+    assert_eq!(mac.to_string(), "01:02:03:0a:0b:ff");
+    assert_eq!("01-02-03-0a-0b-ff".parse::<MacAddress>().unwrap(), mac);
+
+    assert_eq!("01:02:03:0a:0b".parse::<MacAddress>().unwrap_err(), ParseError::InvalidLength(14));
+    assert_eq!("01:02-03:0a:0b:ff".parse::<MacAddress>().unwrap_err(), ParseError::InvalidByte(b'-', 5));
+    assert_eq!("01.02.03.0a.0b.ff".parse::<MacAddress>().unwrap_err(), ParseError::InvalidByte(b'.', 2));
+    assert_eq!("01:02:03:0g:0b:ff".parse::<MacAddress>().unwrap_err(), ParseError::InvalidByte(b'g', 10));
+
+    let time = core::time::Duration::new(1_496_854_535, 812_946_000);
+    let uuid = Uuid::v1_mac(lolid::Timestamp::from_unix(time), MacAddress::new([1, 2, 3, 4, 5, 6]));
+    assert_eq!(uuid.to_str().as_str(), "20616934-4ba2-11e7-8000-010203040506");
+}
+
+#[test]
+fn check_node_mac() {
+    let uuid = Uuid::parse_str("20616934-4ba2-11e7-8000-0a0b0c0d0e0f").unwrap();
+    assert_eq!(uuid.node_mac().unwrap(), "0a:0b:0c:0d:0e:0f");
+
+    let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+    assert!(uuid.node_mac().is_none());
+}
+
+#[test]
+fn check_clock_sequence() {
+    const MAC: [u8; 6] = [1, 2, 3, 4, 5, 6];
+    let time = lolid::Timestamp::from_unix(core::time::Duration::new(1_496_854_535, 812_946_000));
+
+    assert_eq!(Uuid::v1(time, MAC).get_clock_sequence(), Some(0));
+    assert_eq!(Uuid::v1(time.set_counter(1), MAC).get_clock_sequence(), Some(1));
+    assert_eq!(Uuid::v1(time.set_counter(0x3fff), MAC).get_clock_sequence(), Some(0x3fff));
+    assert_eq!(Uuid::v1(time.set_counter(0x1234), MAC).get_clock_sequence(), Some(0x1234));
+
+    let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+    assert_eq!(uuid.get_clock_sequence(), None);
+}
+
+#[test]
+fn check_v1_v6_conversion() {
+    //Test vectors from RFC9562
+    let v1 = Uuid::parse_str("C232AB00-9414-11EC-B3C8-9F6BDECED846").unwrap();
+    let v6 = Uuid::parse_str("1EC9414C-232A-6B00-B3C8-9F6BDECED846").unwrap();
+
+    assert_eq!(v1.v1_to_v6(), Some(v6));
+    assert_eq!(v6.v6_to_v1(), Some(v1));
+    assert!(v1.v6_to_v1().is_none());
+    assert!(v6.v1_to_v6().is_none());
+
+    assert!(v6.is_version(lolid::Version::SortMac));
+    assert_eq!(v6.get_clock_sequence(), v1.get_clock_sequence());
+    assert_eq!(v6.node_mac().unwrap(), "9f:6b:de:ce:d8:46");
+
+    let time = lolid::Timestamp::from_unix(core::time::Duration::new(1_496_854_535, 812_946_000)).set_counter(5);
+    let v1 = Uuid::v1(time, [1, 2, 3, 4, 5, 6]);
+    assert_eq!(v1.v1_to_v6().unwrap().v6_to_v1(), Some(v1));
+}
+
+#[test]
+fn check_v7_from_parts() {
+    //Test vector from RFC9562
+    const UUID: Uuid = Uuid::v7_from_parts(0x017F22E279B0, [0x0C, 0xC3, 0x18, 0xC4, 0xDC, 0x0C, 0x0C, 0x07, 0x39, 0x8F]);
+    assert!(UUID.is_version(lolid::Version::SortRand));
+    assert!(UUID.is_variant());
+    assert_eq!(UUID.to_str(), "017f22e2-79b0-7cc3-98c4-dc0c0c07398f");
+    assert_eq!(UUID.v7_unix_ms(), Some(0x017F22E279B0));
+    assert_eq!(Uuid::nil().v7_unix_ms(), None);
+
+    let uuid = Uuid::v7_from_parts(u64::MAX, [0xff; 10]);
+    assert_eq!(uuid.to_str(), "ffffffff-ffff-7fff-bfff-ffffffffffff");
+    assert_eq!(uuid.v7_unix_ms(), Some(0xFFFF_FFFF_FFFF));
+}
+
+#[test]
+fn check_v7_precise_from_parts() {
+    const RAND: [u8; 8] = [0x18, 0xC4, 0xDC, 0x0C, 0x0C, 0x07, 0x39, 0x8F];
+
+    let time = core::time::Duration::from_millis(0x017F22E279B0);
+    let uuid = Uuid::v7_precise_from_parts(time, RAND);
+    assert!(uuid.is_version(lolid::Version::SortRand));
+    assert!(uuid.is_variant());
+    assert_eq!(uuid.to_str(), "017f22e2-79b0-7000-98c4-dc0c0c07398f");
+
+    let uuid = Uuid::v7_precise_from_parts(time + core::time::Duration::from_nanos(500_000), RAND);
+    assert_eq!(uuid.to_str(), "017f22e2-79b0-7800-98c4-dc0c0c07398f");
+
+    let uuid = Uuid::v7_precise_from_parts(time + core::time::Duration::from_nanos(999_999), RAND);
+    assert_eq!(uuid.to_str(), "017f22e2-79b0-7fff-98c4-dc0c0c07398f");
+
+    let before = Uuid::v7_precise_from_parts(time + core::time::Duration::from_nanos(1_000), [0xff; 8]);
+    let after = Uuid::v7_precise_from_parts(time + core::time::Duration::from_nanos(2_000), [0; 8]);
+    assert!(before < after);
+}
+
+#[test]
+fn check_v8_with_time() {
+    let uuid = Uuid::v8_with_time(0x017F22E279B0, &[]);
+    assert!(uuid.is_version(lolid::Version::Custom));
+    assert!(uuid.is_variant());
+    assert_eq!(uuid.to_str(), "017f22e2-79b0-8000-8000-000000000000");
+
+    let uuid = Uuid::v8_with_time(0x017F22E279B0, &[0xff; 10]);
+    assert_eq!(uuid.to_str(), "017f22e2-79b0-8fff-bfff-ffffffffffff");
+
+    let uuid = Uuid::v8_with_time(0x017F22E279B0, &[0xff; 20]);
+    assert_eq!(uuid.to_str(), "017f22e2-79b0-8fff-bfff-ffffffffffff");
+
+    //First 12 bits go to rand_a, following 62 bits to rand_b
+    let uuid = Uuid::v8_with_time(0x017F22E279B0, &[0xab, 0xcd, 0x80]);
+    assert_eq!(uuid.to_str(), "017f22e2-79b0-8abc-b600-000000000000");
+
+    let before = Uuid::v8_with_time(1, &[0xff; 10]);
+    let after = Uuid::v8_with_time(2, &[0; 10]);
+    assert!(before < after);
+}
+
+#[test]
+fn check_dce_fields() {
+    //UID 1000 of Person domain
+    let uuid = Uuid::parse_str("000003e8-cbb5-21ec-9a00-9f6bdeced846").unwrap();
+    assert!(uuid.is_version(lolid::Version::Dce));
+    assert_eq!(uuid.dce_local_id(), Some(1000));
+    assert_eq!(uuid.dce_domain(), Some(0));
+
+    let uuid = Uuid::parse_str("000003e8-cbb5-21ec-9a01-9f6bdeced846").unwrap();
+    assert_eq!(uuid.dce_domain(), Some(1));
+
+    let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+    assert_eq!(uuid.dce_local_id(), None);
+    assert_eq!(uuid.dce_domain(), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_v8_fingerprint() {
+    let fingerprint = Uuid::thread_fingerprint();
+    assert_eq!(fingerprint, Uuid::thread_fingerprint());
+
+    let uuid = Uuid::v8_fingerprint();
+    assert!(uuid.is_version(lolid::Version::Custom));
+    assert!(uuid.is_variant());
+    assert_eq!(uuid.fingerprint(), Some(fingerprint));
+    assert_ne!(uuid, Uuid::v8_fingerprint());
+
+    let other = std::thread::spawn(|| (Uuid::thread_fingerprint(), Uuid::v8_fingerprint())).join().unwrap();
+    assert_ne!(other.0, fingerprint);
+    assert_eq!(other.1.fingerprint(), Some(other.0));
+
+    assert_eq!(Uuid::v8_with_time(1, &[0xde, 0xad, 0xbe, 0xef]).fingerprint(), Some(0xdeadbeef));
+    assert_eq!(Uuid::nil().fingerprint(), None);
+}
+
+#[cfg(all(feature = "std", feature = "osrng"))]
+#[test]
+fn check_v7_per_thread() {
+    fn generate() -> std::vec::Vec<Uuid> {
+        let mut result = std::vec::Vec::with_capacity(10_000);
+        for _ in 0..10_000 {
+            let uuid = Uuid::v7_per_thread();
+            assert!(uuid.is_version(lolid::Version::SortRand));
+            assert!(uuid.is_variant());
+            result.push(uuid);
+        }
+        assert!(result.windows(2).all(|pair| pair[0] < pair[1]));
+        result
+    }
+
+    let threads: std::vec::Vec<_> = (0..4).map(|_| std::thread::spawn(generate)).collect();
+    let mut all = std::collections::HashSet::new();
+    for thread in threads {
+        for uuid in thread.join().unwrap() {
+            assert!(all.insert(uuid));
+        }
+    }
+}
+
+#[cfg(feature = "prng")]
+#[test]
+fn check_random_uuid4_prng_stream() {
+    static FIRST: lolid::PrngStream = lolid::PrngStream::new(0x548c9decbce65297);
+    static SECOND: lolid::PrngStream = lolid::PrngStream::new(0x548c9decbce65297);
+    static OTHER: lolid::PrngStream = lolid::PrngStream::new(0x96e3b8f2a7d4c51d);
+
+    let uuid = Uuid::v4_prng_stream(&FIRST);
+    assert!(uuid.is_version(lolid::Version::Random));
+    assert!(uuid.is_variant());
+    assert_eq!(uuid, Uuid::v4_prng_stream(&SECOND));
+    assert_ne!(uuid, Uuid::v4_prng_stream(&OTHER));
+    assert_ne!(uuid, Uuid::v4_prng_stream(&FIRST));
+}
+
+#[cfg(feature = "prng")]
+#[test]
+fn check_random_uuid4_prng_entropy() {
+    static FIRST: lolid::PrngStream = lolid::PrngStream::new(0x548c9decbce65297);
+    static SECOND: lolid::PrngStream = lolid::PrngStream::new(0x548c9decbce65297);
+    static THIRD: lolid::PrngStream = lolid::PrngStream::new(0x548c9decbce65297);
+
+    FIRST.mix_entropy(b"device-1");
+    SECOND.mix_entropy(b"device-2");
+    THIRD.mix_entropy(b"device-1");
+
+    let uuid = Uuid::v4_prng_stream(&FIRST);
+    assert!(uuid.is_version(lolid::Version::Random));
+    assert!(uuid.is_variant());
+    assert_ne!(uuid, Uuid::v4_prng_stream(&SECOND));
+    assert_eq!(uuid, Uuid::v4_prng_stream(&THIRD));
+
+    Uuid::v4_prng_mix_entropy(b"boot-nonce");
+    assert_ne!(Uuid::v4_prng(), Uuid::v4_prng());
+}
+
+#[cfg(any(feature = "osrng", feature = "prng"))]
+#[test]
+fn check_new() {
+    let uuid = Uuid::new();
+    assert!(uuid.is_variant());
+    assert_ne!(uuid, Uuid::new());
+
+    #[cfg(feature = "std")]
+    assert!(uuid.is_version(lolid::Version::SortRand));
+    #[cfg(not(feature = "std"))]
+    assert!(uuid.is_version(lolid::Version::Random));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn check_owned_strings() {
+    let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+    assert_eq!(uuid.to_hyphenated_string(), "60ecb7b6-ba34-5aad-a9ef-9020b1ea210a");
+    assert_eq!(uuid.to_simple_string(), "60ecb7b6ba345aada9ef9020b1ea210a");
+    assert_eq!(Uuid::parse_str(&uuid.to_simple_string()).unwrap(), uuid);
+    assert_eq!(String::from(uuid), "60ecb7b6-ba34-5aad-a9ef-9020b1ea210a");
+    assert_eq!(String::from(uuid.to_urn_str()), "urn:uuid:60ecb7b6-ba34-5aad-a9ef-9020b1ea210a");
+}
+
+#[test]
+fn check_into_integer_and_bytes() {
+    let uuid = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap();
+
+    let bytes: [u8; 16] = uuid.into();
+    assert_eq!(bytes, uuid.bytes());
+
+    let num: u128 = uuid.into();
+    assert_eq!(num, 0x60ecb7b6_ba34_5aad_a9ef_9020b1ea210a);
+    assert_eq!(u128::from(Uuid::nil()), 0);
+
+    let bigger = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210b").unwrap();
+    assert!(u128::from(bigger) > num);
+    assert!(bigger > uuid);
+}
+
+#[test]
+fn check_fields() {
+    const D4: [u8; 8] = [0x86, 0x47, 0x9d, 0xc5, 0x4e, 0x1e, 0xe1, 0xe8];
+
+    let uuid = Uuid::from_guid(0x4a35229d, 0x5527, 0x4f30, D4);
+    assert_eq!(uuid.to_fields(), (0x4a35229d, 0x5527, 0x4f30, D4));
+    assert_eq!(uuid.to_fields_le(), (0x9d22354a, 0x2755, 0x304f, D4));
+
+    let uuid = Uuid::from_fields_le(0x4a35229d, 0x5527, 0x4f30, D4);
+    assert_eq!(uuid.to_str(), "9d22354a-2755-304f-8647-9dc54e1ee1e8");
+    assert_eq!(uuid.to_fields_le(), (0x4a35229d, 0x5527, 0x4f30, D4));
+    assert_eq!(uuid.to_fields(), (0x9d22354a, 0x2755, 0x304f, D4));
+}
+
+#[cfg(feature = "osrng")]
+#[test]
+fn check_random_uuid4_entropy_pool() {
+    let mut pool = lolid::EntropyPool::new();
+    let mut generated = [Uuid::nil(); lolid::EntropyPool::SIZE / 16 + 1];
+    for uuid in generated.iter_mut() {
+        *uuid = pool.v4();
+        assert!(uuid.is_version(lolid::Version::Random));
+        assert!(uuid.is_variant());
+    }
+
+    generated.sort();
+    assert!(generated.windows(2).all(|pair| pair[0] != pair[1]));
+
+    #[cfg(feature = "std")]
+    {
+        let uuid = Uuid::v4_pooled();
+        assert!(uuid.is_version(lolid::Version::Random));
+        assert!(uuid.is_variant());
+        assert_ne!(uuid, Uuid::v4_pooled());
+    }
+}
+
+#[cfg(feature = "osrng")]
+#[test]
+fn check_random_uuid4_batch() {
+    let mut generated = [Uuid::nil(); 100];
+    Uuid::v4_batch(&mut generated);
+    for uuid in generated.iter() {
+        assert!(uuid.is_version(lolid::Version::Random));
+        assert!(uuid.is_variant());
+    }
+
+    generated.sort();
+    assert!(generated.windows(2).all(|pair| pair[0] != pair[1]));
+
+    Uuid::v4_batch(&mut []);
+
+    let mut filled = [Uuid::nil(); 3];
+    Uuid::v4_fill(&mut filled);
+    assert!(filled.iter().all(|uuid| uuid.is_version(lolid::Version::Random) && uuid.is_variant()));
+    assert!(filled[0] != filled[1] && filled[1] != filled[2]);
+}
+
+#[cfg(all(feature = "std", feature = "osrng"))]
+#[test]
+fn check_uuid_pool() {
+    let pool = std::sync::Arc::new(lolid::UuidPool::new(64, Uuid::v4));
+    assert_eq!(pool.capacity(), 64);
+
+    let threads: std::vec::Vec<_> = (0..4).map(|_| {
+        let pool = pool.clone();
+        std::thread::spawn(move || (0..1000).map(|_| pool.next()).collect::<std::vec::Vec<_>>())
+    }).collect();
+
+    let mut all = std::collections::HashSet::new();
+    for thread in threads {
+        for uuid in thread.join().unwrap() {
+            assert!(uuid.is_version(lolid::Version::Random));
+            assert!(uuid.is_variant());
+            assert!(all.insert(uuid));
+        }
+    }
+    assert_eq!(all.len(), 4000);
+    assert_eq!(lolid::UuidPool::new(10, Uuid::v4).capacity(), 16);
+}
+
+#[cfg(all(feature = "async", feature = "osrng"))]
+#[test]
+fn check_uuid_pool_async() {
+    use core::future::Future;
+    use core::task::{Context, Poll};
+
+    struct ThreadWaker(std::thread::Thread);
+
+    impl std::task::Wake for ThreadWaker {
+        fn wake(self: std::sync::Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        let mut fut = Box::pin(fut);
+        let waker = std::sync::Arc::new(ThreadWaker(std::thread::current())).into();
+        let mut ctx = Context::from_waker(&waker);
+        loop {
+            match fut.as_mut().poll(&mut ctx) {
+                Poll::Ready(result) => break result,
+                Poll::Pending => std::thread::park(),
+            }
+        }
+    }
+
+    let pool = lolid::UuidPool::new(8, Uuid::v4);
+    let mut all = std::collections::HashSet::new();
+    block_on(async {
+        //Drains pool multiple times over, relying on background refill
+        for _ in 0..100 {
+            let uuid = pool.next_async().await;
+            assert!(uuid.is_version(lolid::Version::Random));
+            assert!(all.insert(uuid));
+        }
+    });
+    assert_eq!(all.len(), 100);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_age() {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    let hour_ago = SystemTime::now().duration_since(UNIX_EPOCH).unwrap() - Duration::from_secs(3600);
+
+    let uuid = Uuid::v1(lolid::Timestamp::from_unix(hour_ago), [1, 2, 3, 4, 5, 6]);
+    let age = uuid.age().unwrap();
+    assert!(age >= Duration::from_secs(3600) && age < Duration::from_secs(3660));
+    let age = uuid.v1_to_v6().unwrap().age().unwrap();
+    assert!(age >= Duration::from_secs(3600) && age < Duration::from_secs(3660));
+
+    let uuid = Uuid::v7_from_parts(hour_ago.as_millis() as u64, [0; 10]);
+    let age = uuid.age().unwrap();
+    assert!(age >= Duration::from_secs(3600) && age < Duration::from_secs(3660));
+
+    let future = Uuid::v7_from_parts(hour_ago.as_millis() as u64 + 7_200_000, [0; 10]);
+    assert_eq!(future.age(), None);
+    assert_eq!(Uuid::v4_from([0xab; 16]).age(), None);
+    assert_eq!(Uuid::nil().age(), None);
+}
+
+#[test]
+fn check_oid() {
+    let uuid = Uuid::parse_str("f81d4fae-7dec-11d0-a765-00a0c91e6bf6").unwrap();
+    let oid = uuid.to_oid_str();
+    assert_eq!(oid, "2.25.329800735698586629295641978511506172918");
+    assert_eq!(Uuid::parse_oid_str(&oid).unwrap(), uuid);
+
+    assert_eq!(Uuid::nil().to_oid_str(), "2.25.0");
+    assert_eq!(Uuid::parse_oid_str("2.25.0").unwrap(), Uuid::nil());
+    let max = Uuid::from_bytes([0xff; 16]);
+    assert_eq!(max.to_oid_str(), "2.25.340282366920938463463374607431768211455");
+    assert_eq!(Uuid::parse_oid_str(&max.to_oid_str()).unwrap(), max);
+
+    assert_eq!(Uuid::parse_oid_str("2.25.").unwrap_err(), lolid::DecodeError::InvalidLength(5));
+    assert_eq!(Uuid::parse_oid_str("2.26.1").unwrap_err(), lolid::DecodeError::InvalidDigit(b'6', 3));
+    assert_eq!(Uuid::parse_oid_str("2.25.01").unwrap_err(), lolid::DecodeError::InvalidDigit(b'0', 5));
+    assert_eq!(Uuid::parse_oid_str("2.25.1a").unwrap_err(), lolid::DecodeError::InvalidDigit(b'a', 6));
+    assert_eq!(Uuid::parse_oid_str("2.25.340282366920938463463374607431768211456").unwrap_err(), lolid::DecodeError::Overflow(43));
+}
+
+lolid::define_uuid_type!(
+    ///Test identifier
+    pub UserId
+);
+
+#[test]
+fn check_define_uuid_type() {
+    let uuid = Uuid::parse_str("f81d4fae-7dec-11d0-a765-00a0c91e6bf6").unwrap();
+    let id = UserId::from(uuid);
+    assert_eq!(*id.as_uuid(), uuid);
+    assert_eq!(Uuid::from(id), uuid);
+    assert_eq!(id.into_uuid(), uuid);
+    assert_eq!(UserId::default(), UserId::new(Uuid::nil()));
+    assert_eq!(id.to_string(), "f81d4fae-7dec-11d0-a765-00a0c91e6bf6");
+    assert_eq!(format!("{:?}", id), "UserId(f81d4fae-7dec-11d0-a765-00a0c91e6bf6)");
+    assert_eq!("f81d4fae-7dec-11d0-a765-00a0c91e6bf6".parse::<UserId>().unwrap(), id);
+    assert!("f81d4fae".parse::<UserId>().is_err());
+}
+
+#[cfg(feature = "collections")]
+#[test]
+fn check_uuid_map() {
+    use lolid::collections::UuidMap;
+
+    let mut map = UuidMap::new();
+    assert!(map.is_empty());
+    assert_eq!(map.get(&Uuid::nil()), None);
+
+    //Sequential keys are worst case, as they are not random
+    for idx in 0..10_000u128 {
+        assert_eq!(map.insert(Uuid::from_bytes(idx.to_be_bytes()), idx), None);
+    }
+    assert_eq!(map.len(), 10_000);
+    assert!(map.capacity() >= 10_000);
+
+    for idx in 0..10_000u128 {
+        let key = Uuid::from_bytes(idx.to_be_bytes());
+        assert_eq!(map.get(&key), Some(&idx));
+    }
+    assert!(!map.contains_key(&Uuid::from_bytes([0xff; 16])));
+
+    let key = Uuid::from_bytes(5u128.to_be_bytes());
+    assert_eq!(map.insert(key, 50), Some(5));
+    *map.get_mut(&key).unwrap() += 1;
+    assert_eq!(map.get(&key), Some(&51));
+
+    for idx in (0..10_000u128).step_by(2) {
+        assert!(map.remove(&Uuid::from_bytes(idx.to_be_bytes())).is_some());
+    }
+    assert_eq!(map.len(), 5_000);
+    assert_eq!(map.remove(&Uuid::nil()), None);
+    assert_eq!(map.iter().count(), 5_000);
+    assert!(map.keys().all(|key| u128::from(*key) % 2 == 1));
+
+    for idx in (0..10_000u128).step_by(2) {
+        assert_eq!(map.insert(Uuid::from_bytes(idx.to_be_bytes()), idx), None);
+    }
+    assert_eq!(map.len(), 10_000);
+
+    map.clear();
+    assert!(map.is_empty());
+    assert_eq!(map.get(&key), None);
+
+    let map: UuidMap<_> = (0..100u128).map(|idx| (Uuid::from_bytes(idx.to_le_bytes()), idx)).collect();
+    assert_eq!(map.len(), 100);
+    assert_eq!(UuidMap::<u8>::with_capacity(7).capacity(), 7);
+}
+
+#[test]
+fn check_bloom_filter() {
+    use lolid::bloom::BloomFilter;
+
+    //splitmix64 to get reproducible random UUIDs
+    let mut state = 0u64;
+    let mut next_uuid = move || {
+        let mut result = [0u8; 16];
+        for chunk in result.chunks_mut(8) {
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut value = state;
+            value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            chunk.copy_from_slice(&(value ^ (value >> 31)).to_le_bytes());
+        }
+        Uuid::v4_from(result)
+    };
+
+    let mut filter = BloomFilter::from_storage([0u64; 16], 3);
+    assert_eq!(filter.bits(), 1024);
+    let uuid = next_uuid();
+    assert!(!filter.contains(&uuid));
+    assert!(!filter.insert(&uuid));
+    assert!(filter.insert(&uuid));
+    assert!(filter.contains(&uuid));
+    filter.clear();
+    assert!(!filter.contains(&uuid));
+
+    //Number of bits is not power of two
+    let mut filter = BloomFilter::from_storage([0u64; 3], 4);
+    let uuid = next_uuid();
+    assert!(!filter.insert(&uuid));
+    assert!(filter.contains(&uuid));
+    let set_bits: u32 = filter.into_inner().iter().map(|word| word.count_ones()).sum();
+    assert!(set_bits > 0 && set_bits <= 4);
+
+    #[cfg(feature = "alloc")]
+    {
+        let mut filter = BloomFilter::with_rate(10_000, 0.01);
+        assert_eq!(filter.hashes(), 7);
+        assert!(filter.bits() >= 95_000 && filter.bits() <= 97_000);
+
+        let inserted: std::vec::Vec<_> = (0..10_000).map(|_| next_uuid()).collect();
+        for uuid in inserted.iter() {
+            filter.insert(uuid);
+        }
+        assert!(inserted.iter().all(|uuid| filter.contains(uuid)));
+
+        let false_positives = (0..10_000).map(|_| next_uuid()).filter(|uuid| filter.contains(uuid)).count();
+        assert!(false_positives < 200, "Too many false positives {}", false_positives);
+    }
+}
+
+#[cfg(feature = "collections")]
+#[test]
+fn check_uuid_set() {
+    use lolid::collections::UuidSet;
+
+    let mut set: UuidSet = (0..1000u128).map(|idx| Uuid::from_bytes(idx.to_be_bytes())).collect();
+    assert_eq!(set.len(), 1000);
+    assert!(!set.insert(Uuid::from_bytes(1u128.to_be_bytes())));
+    assert!(set.insert(Uuid::nil().set_version(lolid::Version::Random)));
+    assert!(set.remove(&Uuid::nil().set_version(lolid::Version::Random)));
+    assert!(!set.remove(&Uuid::nil().set_version(lolid::Version::Random)));
+
+    let other: UuidSet = (500..1500u128).map(|idx| Uuid::from_bytes(idx.to_be_bytes())).collect();
+
+    let intersection = set.intersection(&other);
+    assert_eq!(intersection.len(), 500);
+    assert!(intersection.iter().all(|uuid| (500..1000).contains(&u128::from(*uuid))));
+    assert_eq!(other.intersection(&set).len(), 500);
+
+    let union = set.union(&other);
+    assert_eq!(union.len(), 1500);
+    assert!((0..1500u128).all(|idx| union.contains(&Uuid::from_bytes(idx.to_be_bytes()))));
+
+    set.clear();
+    assert!(set.is_empty());
+    assert!(set.intersection(&other).is_empty());
+    assert_eq!(set.union(&other).len(), 1000);
+}
+
+#[test]
+fn check_delta_decode() {
+    use lolid::delta::{Decoder, DeltaError};
+
+    //Timestamp 0x017F22E279B0 as varint, followed by rest of UUID
+    let mut input = [0u8; 16];
+    input[..6].copy_from_slice(&[0xb0, 0xf3, 0x89, 0x97, 0xf2, 0x2f]);
+    input[6..].copy_from_slice(&[0x7c, 0xc3, 0x98, 0xc4, 0xdc, 0x0c, 0x0c, 0x07, 0x39, 0x8f]);
+    let mut decoder = Decoder::new(&input);
+    assert_eq!(decoder.next().unwrap().unwrap().to_str(), "017f22e2-79b0-7cc3-98c4-dc0c0c07398f");
+    assert_eq!(decoder.next(), None);
+
+    let mut decoder = Decoder::new(&input[..1]);
+    assert_eq!(decoder.next(), Some(Err(DeltaError::Truncated)));
+    assert_eq!(decoder.next(), None);
+
+    let mut decoder = Decoder::new(&[0xff; 8]);
+    assert_eq!(decoder.next(), Some(Err(DeltaError::Overflow)));
+    assert_eq!(decoder.next(), None);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn check_delta_roundtrip() {
+    let mut uuids = std::vec::Vec::new();
+    for idx in 0..1000u64 {
+        let mut rand = [0u8; 10];
+        rand[..8].copy_from_slice(&idx.wrapping_mul(0x9e37_79b9_7f4a_7c15).to_le_bytes());
+        uuids.push(Uuid::v7_from_parts(1_645_557_742_000 + idx / 3, rand));
+    }
+
+    let mut encoded = std::vec::Vec::new();
+    lolid::delta::encode(&uuids, &mut encoded).unwrap();
+    assert!(encoded.len() < uuids.len() * 12);
+
+    let mut decoded = std::vec::Vec::new();
+    lolid::delta::decode(&encoded, &mut decoded).unwrap();
+    assert_eq!(decoded, uuids);
+
+    uuids.swap(10, 500);
+    let mut encoded = std::vec::Vec::new();
+    assert_eq!(lolid::delta::encode(&uuids, &mut encoded).unwrap_err(), lolid::delta::DeltaError::Unsorted(11));
+    assert!(encoded.is_empty());
+}
+
+#[test]
+fn check_uuid_generator() {
+    use lolid::UuidGenerator;
+
+    fn generate_pair(generator: &impl UuidGenerator) -> (Uuid, Uuid) {
+        (generator.generate(), generator.generate())
+    }
+
+    let counter = core::cell::Cell::new(0u128);
+    let mock = || {
+        counter.set(counter.get() + 1);
+        Uuid::from_bytes(counter.get().to_be_bytes())
+    };
+    let (first, second) = generate_pair(&mock);
+    assert_eq!(u128::from(first), 1);
+    assert_eq!(u128::from(second), 2);
+
+    let (first, second) = generate_pair(&Uuid::nil);
+    assert_eq!(first, second);
+
+    #[cfg(feature = "osrng")]
+    {
+        let (first, second) = generate_pair(&Uuid::v4);
+        assert_ne!(first, second);
+    }
+
+    #[cfg(feature = "prng")]
+    {
+        let (first, second) = generate_pair(&lolid::PrngStream::new(10));
+        assert_ne!(first, second);
+        assert_eq!(lolid::PrngStream::new(10).generate(), first);
+    }
+
+    #[cfg(all(feature = "std", feature = "osrng"))]
+    {
+        let (first, second) = generate_pair(&lolid::UuidPool::new(4, Uuid::v4));
+        assert_ne!(first, second);
+    }
+}
+
+#[test]
+fn check_migrate() {
+    let v1 = Uuid::parse_str("C232AB00-9414-11EC-B3C8-9F6BDECED846").unwrap();
+    let v6 = Uuid::parse_str("1EC9414C-232A-6B00-B3C8-9F6BDECED846").unwrap();
+    let v4 = Uuid::v4_from([0xab; 16]);
+
+    let mut uuids = [v1, v4, v1, Uuid::nil()];
+    assert_eq!(lolid::migrate::v1_to_v6_in_place(&mut uuids), 2);
+    assert_eq!(uuids, [v6, v4, v6, Uuid::nil()]);
+    assert_eq!(lolid::migrate::v1_to_v6_in_place(&mut uuids), 0);
+
+    assert_eq!(lolid::migrate::v6_to_v1_in_place(&mut uuids), 2);
+    assert_eq!(uuids, [v1, v4, v1, Uuid::nil()]);
+
+    let rewritten = lolid::migrate::rewrite_in_place(&mut uuids, |uuid| match uuid.is_version(lolid::Version::Random) {
+        true => Some(uuid.set_version(lolid::Version::Custom)),
+        false => None,
+    });
+    assert_eq!(rewritten, 1);
+    assert!(uuids[1].is_version(lolid::Version::Custom));
+}
+
+#[cfg(any(feature = "osrng", feature = "prng"))]
+#[test]
+fn check_random_within() {
+    let start = Uuid::parse_str("40000000-0000-0000-0000-000000000000").unwrap();
+    let end = Uuid::parse_str("40000000-0000-0000-0000-000000000010").unwrap();
+    let mut seen = [false; 16];
+    for _ in 0..1000 {
+        let uuid = Uuid::random_within(start..end);
+        assert!(uuid >= start && uuid < end);
+        seen[(u128::from(uuid) - u128::from(start)) as usize] = true;
+    }
+    assert!(seen.iter().all(|seen| *seen));
+
+    let uuid = Uuid::random_within(start..Uuid::from_bytes((u128::from(start) + 1).to_be_bytes()));
+    assert_eq!(uuid, start);
+
+    let end = Uuid::from_bytes([0xff; 16]);
+    let uuid = Uuid::random_within(Uuid::nil()..end);
+    assert!(uuid < end);
+}
+
+#[cfg(any(feature = "osrng", feature = "prng"))]
+#[test]
+fn check_v7() {
+    let time = core::time::Duration::from_millis(0x017F22E279B0);
+    let first = Uuid::v7(time);
+    let second = Uuid::v7(time);
+    assert_ne!(first, second);
+    for uuid in [first, second].iter() {
+        assert!(uuid.is_version(lolid::Version::SortRand));
+        assert!(uuid.is_variant());
+        assert_eq!(uuid.v7_unix_ms(), Some(0x017F22E279B0));
+    }
+
+    #[cfg(feature = "std")]
+    {
+        let now = Uuid::v7_now();
+        assert!(now.is_version(lolid::Version::SortRand));
+        assert!(now > first);
+        assert!(now.age().unwrap() < core::time::Duration::from_secs(60));
+    }
+}
+
+#[test]
+fn check_v8() {
+    const UUID: Uuid = Uuid::v8([0xff; 16]);
+    assert_eq!(UUID.to_str(), "ffffffff-ffff-8fff-bfff-ffffffffffff");
+    assert!(UUID.is_version(lolid::Version::Custom));
+    assert!(UUID.is_variant());
+
+    let uuid = Uuid::v8([0; 16]);
+    assert_eq!(uuid.to_str(), "00000000-0000-8000-8000-000000000000");
+}
+
+#[test]
+fn check_v2() {
+    use lolid::Domain;
+
+    let timestamp = lolid::Timestamp::from_parts(0x1EC_9414_C232_AB00, 0x33C8);
+    let uuid = Uuid::v2(Domain::Group, 1000, timestamp, [0x9F, 0x6B, 0xDE, 0xCE, 0xD8, 0x46]);
+    assert_eq!(uuid.to_str(), "000003e8-9414-21ec-b301-9f6bdeced846");
+    assert!(uuid.is_version(lolid::Version::Dce));
+    assert!(uuid.is_variant());
+    assert_eq!(uuid.dce_local_id(), Some(1000));
+    assert_eq!(uuid.dce_domain(), Some(Domain::Group.as_u8()));
+    assert_eq!(Domain::from_u8(uuid.dce_domain().unwrap()), Some(Domain::Group));
+    assert_eq!(uuid.node(), [0x9F, 0x6B, 0xDE, 0xCE, 0xD8, 0x46]);
+
+    assert_eq!(Domain::from_u8(0), Some(Domain::Person));
+    assert_eq!(Domain::from_u8(2), Some(Domain::Org));
+    assert_eq!(Domain::from_u8(3), None);
+}
+
+#[test]
+fn check_v7_generator() {
+    let time = core::cell::Cell::new(1_645_557_742_000u64);
+    let mut generator = lolid::V7Generator::new(|| time.get());
+
+    let first = generator.next_from_random([0xff; 8]);
+    assert_eq!(first.to_str(), "017f22e2-79b0-7000-bfff-ffffffffffff");
+    let second = generator.next_from_random([0; 8]);
+    assert_eq!(second.to_str(), "017f22e2-79b0-7001-8000-000000000000");
+    assert!(second > first);
+
+    //Clock goes backwards
+    time.set(time.get() - 10);
+    let third = generator.next_from_random([0; 8]);
+    assert_eq!(third.to_str(), "017f22e2-79b0-7002-8000-000000000000");
+
+    //Counter overflow
+    let mut last = third;
+    for _ in 3..0x1000 {
+        let next = generator.next_from_random([0; 8]);
+        assert!(next > last);
+        last = next;
+    }
+    assert_eq!(last.to_str(), "017f22e2-79b0-7fff-8000-000000000000");
+    let next = generator.next_from_random([0; 8]);
+    assert_eq!(next.to_str(), "017f22e2-79b1-7000-8000-000000000000");
+
+    time.set(time.get() + 100);
+    let next = generator.next_from_random([0; 8]);
+    assert_eq!(next.v7_unix_ms(), Some(1_645_557_742_090));
+
+    #[cfg(any(feature = "osrng", feature = "prng"))]
+    {
+        let first = generator.next();
+        let second = generator.next();
+        assert!(second > first);
+        assert!(first.is_version(lolid::Version::SortRand));
+        assert!(first.is_variant());
+    }
+
+    #[cfg(all(feature = "std", any(feature = "osrng", feature = "prng")))]
+    {
+        let mut generator = lolid::V7Generator::system();
+        let first = generator.next();
+        assert!(generator.next() > first);
+        assert!(first.age().unwrap() < core::time::Duration::from_secs(60));
+    }
+}
+
+#[cfg(any(feature = "osrng", feature = "prng"))]
+#[test]
+fn check_v7_precise() {
+    let time = core::time::Duration::new(1_645_557_742, 500_000);
+    let first = Uuid::v7_precise(time);
+    let second = Uuid::v7_precise(time + core::time::Duration::from_micros(1));
+    assert!(first.to_str().starts_with("017f22e2-79b0-7800-"));
+    assert!(second.to_str().starts_with("017f22e2-79b0-7804-"));
+    assert!(second > first);
+    assert!(first.is_variant());
+    assert_eq!(first.v7_unix_ms(), Some(1_645_557_742_000));
+}
+
+#[test]
+fn check_to_v6_to_v1() {
+    const V1: Uuid = Uuid::from_bytes([0xC2, 0x32, 0xAB, 0x00, 0x94, 0x14, 0x11, 0xEC, 0xB3, 0xC8, 0x9F, 0x6B, 0xDE, 0xCE, 0xD8, 0x46]);
+    const V6: Option<Uuid> = V1.to_v6();
+
+    let v6 = V6.unwrap();
+    assert_eq!(v6.to_str(), "1ec9414c-232a-6b00-b3c8-9f6bdeced846");
+    assert_eq!(v6.to_v6(), Some(v6));
+    assert_eq!(v6.to_v1(), Some(V1));
+    assert_eq!(V1.to_v1(), Some(V1));
+
+    let v4 = Uuid::v4_from([0xab; 16]);
+    assert_eq!(v4.to_v6(), None);
+    assert_eq!(v4.to_v1(), None);
+}
+
+#[cfg(feature = "sha256")]
+#[test]
+fn check_v8_sha256() {
+    const UUID: Uuid = Uuid::v8_sha256(lolid::NAMESPACE_DNS, b"www.example.com");
+    assert_eq!(UUID.to_str(), "5c146b14-3c52-8afd-938a-375d0df1fbf6");
+    assert!(UUID.is_version(lolid::Version::Custom));
+    assert!(UUID.is_variant());
+}
+
+#[test]
+fn check_nil_max() {
+    const FLAGS: [bool; 2] = [Uuid::nil().is_nil(), Uuid::max().is_max()];
+    assert_eq!(FLAGS, [true, true]);
+
+    assert_eq!(Uuid::max().to_str(), "ffffffff-ffff-ffff-ffff-ffffffffffff");
+    assert!(!Uuid::max().is_nil());
+    assert!(!Uuid::nil().is_max());
+    assert!(!Uuid::v4_from([0xff; 16]).is_max());
+    assert!(Uuid::nil() < Uuid::max());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn check_v4_with_rng() {
+    struct Counter(u8);
+
+    impl rand_core::RngCore for Counter {
+        fn next_u32(&mut self) -> u32 {
+            rand_core::impls::next_u32_via_fill(self)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            rand_core::impls::next_u64_via_fill(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for byte in dest.iter_mut() {
+                self.0 = self.0.wrapping_add(1);
+                *byte = self.0;
+            }
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    let mut rng = Counter(0);
+    let uuid = Uuid::v4_with_rng(&mut rng);
+    assert_eq!(uuid.to_str(), "01020304-0506-4708-890a-0b0c0d0e0f10");
+    let uuid = Uuid::v4_with_rng(&mut rng as &mut dyn rand_core::RngCore);
+    assert_eq!(uuid.to_str(), "11121314-1516-4718-991a-1b1c1d1e1f20");
+}
+
+#[cfg(all(feature = "md5", feature = "sha1", feature = "sha256"))]
+#[test]
+fn check_name_hasher() {
+    use lolid::NameHasher;
+
+    let hasher = NameHasher::new(lolid::NAMESPACE_DNS).update(b"").update(b"www.").update(b"example").update(b".com");
+    assert_eq!(hasher.finish_v3(), Uuid::v3(lolid::NAMESPACE_DNS, b"www.example.com"));
+    assert_eq!(hasher.finish_v5(), Uuid::v5(lolid::NAMESPACE_DNS, b"www.example.com"));
+    assert_eq!(hasher.finish_v8_sha256(), Uuid::v8_sha256(lolid::NAMESPACE_DNS, b"www.example.com"));
+
+    let long = [b'a'; 150];
+    let hasher = NameHasher::new(lolid::NAMESPACE_URL).update(&long[..70]).update(&long[70..]);
+    assert_eq!(hasher.finish_v5(), Uuid::v5(lolid::NAMESPACE_URL, &long));
+}
+
+#[cfg(all(feature = "md5", feature = "sha1"))]
+#[test]
+fn check_name_str() {
+    const UUID: Uuid = lolid::NAMESPACE_DNS.name_uuid("rust-lang.org");
+    assert_eq!(UUID, Uuid::v5(lolid::NAMESPACE_DNS, b"rust-lang.org"));
+    assert_eq!(Uuid::v5_str(lolid::NAMESPACE_DNS, "rust-lang.org"), UUID);
+    assert_eq!(Uuid::v3_str(lolid::NAMESPACE_DNS, "rust-lang.org"), Uuid::v3(lolid::NAMESPACE_DNS, b"rust-lang.org"));
+}
+
+#[test]
+fn check_version() {
+    use lolid::Version;
+
+    assert_eq!(Uuid::nil().version(), Some(Version::Nil));
+    assert_eq!(Uuid::max().version(), None);
+    assert_eq!(Uuid::v4_from([0; 16]).version(), Some(Version::Random));
+    assert_eq!(Uuid::v8([0; 16]).version(), Some(Version::Custom));
+    assert_eq!(Uuid::from_bytes([0; 16]).set_variant().version(), Some(Version::Unknown));
+    assert_eq!(Uuid::from_bytes([0xff; 16]).set_variant().version(), Some(Version::Unknown));
+    assert_eq!(Uuid::v4_from([0; 16]).set_version(Version::SortRand).version(), Some(Version::SortRand));
+    assert_eq!(Version::Unknown.to_string(), "unknown");
+
+    let uuid = Uuid::v4_from([0; 16]);
+    assert_eq!(uuid.set_version(Version::Unknown), uuid);
+    assert!(!uuid.is_version(Version::Unknown));
+    assert!(Uuid::from_bytes([0xff; 16]).set_variant().is_version(Version::Unknown));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_v1_now_mac() {
+    const MAC: lolid::MacAddress = lolid::MacAddress([0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f]);
+
+    let mut prev = Uuid::v1_now_mac(MAC).to_v6().unwrap();
+    for _ in 0..10_000 {
+        let uuid = Uuid::v1_now_mac(MAC);
+        assert!(uuid.is_version(lolid::Version::Mac));
+        assert_eq!(uuid.node(), MAC.0);
+
+        let uuid = uuid.to_v6().unwrap();
+        assert!(uuid > prev);
+        prev = uuid;
+    }
+}
+
+#[test]
+fn check_comb() {
+    use core::time::Duration;
+
+    //SQL Server compares last group first, going backwards
+    fn sql_server_key(uuid: &Uuid) -> [u8; 16] {
+        let data = uuid.as_bytes();
+        [
+            data[10], data[11], data[12], data[13], data[14], data[15],
+            data[8], data[9], data[6], data[7], data[4], data[5],
+            data[0], data[1], data[2], data[3],
+        ]
+    }
+
+    let uuid = lolid::comb::from_parts(Duration::from_millis(0x017F22E279B0), [0xff; 10]);
+    assert!(uuid.is_version(lolid::Version::Random));
+    assert!(uuid.is_variant());
+    assert_eq!(uuid.to_str(), "ffffffff-ffff-4fff-bfff-017f22e279b0");
+
+    let uuid = lolid::comb::from_parts_datetime(Duration::new(86_400 + 1, 500_000_000), [0; 10]);
+    assert!(uuid.is_version(lolid::Version::Random));
+    assert!(uuid.is_variant());
+    assert_eq!(uuid.to_str(), "00000000-0000-4000-8000-63e0000001c2");
+
+    let mut prev = [lolid::comb::from_parts(Duration::from_secs(1), [0xff; 10]), lolid::comb::from_parts_datetime(Duration::from_secs(1), [0xff; 10])];
+    for idx in 1..1000 {
+        let time = Duration::from_secs(1) + Duration::from_millis(idx * 7);
+        let next = [lolid::comb::from_parts(time, [0; 10]), lolid::comb::from_parts_datetime(time, [0; 10])];
+        assert!(sql_server_key(&next[0]) > sql_server_key(&prev[0]));
+        assert!(sql_server_key(&next[1]) > sql_server_key(&prev[1]));
+        prev = next;
+    }
+
+    #[cfg(all(feature = "std", feature = "osrng"))]
+    {
+        let first = lolid::comb::now();
+        let second = lolid::comb::now_datetime();
+        assert!(first.is_version(lolid::Version::Random));
+        assert!(second.is_version(lolid::Version::Random));
+        assert_ne!(first, lolid::comb::now());
+    }
+}
+
+#[cfg(all(feature = "std", feature = "osrng"))]
+#[test]
+fn check_squuid() {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let before = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as u32;
+    let uuid = Uuid::squuid();
+    let after = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as u32;
+
+    assert!(uuid.is_version(lolid::Version::Random));
+    assert!(uuid.is_variant());
+    let data = uuid.as_bytes();
+    let secs = u32::from_be_bytes([data[0], data[1], data[2], data[3]]);
+    assert!(secs >= before && secs <= after);
+    assert_ne!(uuid, Uuid::squuid());
+}
+
+#[test]
+fn check_variant() {
+    use lolid::Variant;
+
+    assert_eq!(Uuid::nil().variant(), Variant::Ncs);
+    assert_eq!(Uuid::max().variant(), Variant::Future);
+    assert_eq!(Uuid::v4_from([0xff; 16]).variant(), Variant::Rfc4122);
+
+    //Microsoft COM GUID, e.g. IUnknown
+    let uuid = Uuid::parse_str("00000000-0000-0000-c000-000000000046").unwrap();
+    assert_eq!(uuid.variant(), Variant::Microsoft);
+    assert!(!uuid.is_variant());
+
+    for &variant in &[Variant::Ncs, Variant::Rfc4122, Variant::Microsoft, Variant::Future] {
+        for &byte in &[0x00, 0x5a, 0xff] {
+            let uuid = Uuid::from_bytes([byte; 16]).set_variant_of(variant);
+            assert_eq!(uuid.variant(), variant);
+            assert_eq!(uuid.as_bytes()[9], byte);
+        }
+    }
+
+    let uuid = Uuid::from_bytes([0xff; 16]);
+    assert_eq!(uuid.set_variant_of(Variant::Rfc4122), uuid.set_variant());
+    assert_eq!(uuid.set_variant_of(Variant::Microsoft).as_bytes()[8], 0xdf);
+    assert_eq!(uuid.set_variant_of(Variant::Ncs).as_bytes()[8], 0x7f);
+    assert_eq!(Uuid::nil().set_variant_of(Variant::Future).as_bytes()[8], 0xe0);
+}
+
+#[cfg(all(feature = "std", feature = "osrng"))]
+#[test]
+fn check_v7_monotonic() {
+    let threads = (0..4).map(|_| std::thread::spawn(|| {
+        let mut uuids = Vec::with_capacity(10_000);
+        for _ in 0..10_000 {
+            let uuid = Uuid::v7_monotonic();
+            assert!(uuid.is_version(lolid::Version::SortRand));
+            assert!(uuid.is_variant());
+            uuids.push(uuid);
+        }
+        uuids
+    })).collect::<Vec<_>>();
+
+    let mut all = Vec::new();
+    for thread in threads {
+        let uuids = thread.join().unwrap();
+        for pair in uuids.windows(2) {
+            assert!(pair[0] < pair[1]);
+        }
+        all.extend(uuids);
+    }
+
+    all.sort();
+    all.dedup();
+    assert_eq!(all.len(), 40_000);
+}
+
+#[test]
+fn check_simple_str() {
+    const UUID: Uuid = Uuid::from_bytes([0x60, 0xec, 0xb7, 0xb6, 0xba, 0x34, 0x5a, 0xad, 0xa9, 0xef, 0x90, 0x20, 0xb1, 0xea, 0x21, 0x0a]);
+    let text = UUID.to_simple_str();
+    assert_eq!(text, "60ecb7b6ba345aada9ef9020b1ea210a");
+    assert_eq!(Uuid::parse_str(&text).unwrap(), UUID);
+    assert_eq!(Uuid::nil().to_simple_str(), "00000000000000000000000000000000");
+    assert_eq!(Uuid::max().to_simple_str(), "ffffffffffffffffffffffffffffffff");
+}
+
+#[test]
+fn check_braced_str() {
+    const TEXT: lolid::TextRepr<38> = lolid::NAMESPACE_DNS.to_braced_str();
+    assert_eq!(TEXT, "{6ba7b810-9dad-11d1-80b4-00c04fd430c8}");
+    assert_eq!(Uuid::max().to_braced_str(), "{ffffffff-ffff-ffff-ffff-ffffffffffff}");
+}
+
+#[test]
+fn check_urn_str() {
+    const TEXT: lolid::TextRepr<45> = lolid::NAMESPACE_URL.to_urn_str();
+    assert_eq!(TEXT, "urn:uuid:6ba7b811-9dad-11d1-80b4-00c04fd430c8");
+    assert_eq!(Uuid::nil().to_urn_str(), "urn:uuid:00000000-0000-0000-0000-000000000000");
+}
+
+#[test]
+fn check_str_upper() {
+    const TEXT: lolid::TextRepr = lolid::NAMESPACE_DNS.to_str_upper();
+    assert_eq!(TEXT, "6BA7B810-9DAD-11D1-80B4-00C04FD430C8");
+    assert_eq!(Uuid::parse_str(&TEXT).unwrap(), lolid::NAMESPACE_DNS);
+    assert_eq!(Uuid::nil().to_str_upper(), Uuid::nil().to_str());
+
+    const SIMPLE: lolid::TextRepr<32> = lolid::NAMESPACE_DNS.to_simple_str_upper();
+    assert_eq!(SIMPLE, "6BA7B8109DAD11D180B400C04FD430C8");
+}
+
+#[test]
+fn check_hex_fmt() {
+    let uuid = lolid::NAMESPACE_OID;
+    assert_eq!(format!("{:x}", uuid), "6ba7b8129dad11d180b400c04fd430c8");
+    assert_eq!(format!("{:X}", uuid), "6BA7B8129DAD11D180B400C04FD430C8");
+}
+
+#[test]
+fn check_display_flags() {
+    let uuid = lolid::NAMESPACE_DNS;
+    assert_eq!(format!("{}", uuid), "6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+    assert_eq!(format!("{:#}", uuid), "{6ba7b810-9dad-11d1-80b4-00c04fd430c8}");
+    assert_eq!(format!("[{:40}]", uuid), "[6ba7b810-9dad-11d1-80b4-00c04fd430c8    ]");
+    assert_eq!(format!("[{:>40}]", uuid), "[    6ba7b810-9dad-11d1-80b4-00c04fd430c8]");
+    assert_eq!(format!("[{:*^#40}]", uuid), "[*{6ba7b810-9dad-11d1-80b4-00c04fd430c8}*]");
+    assert_eq!(format!("[{:>34x}]", uuid), "[  6ba7b8109dad11d180b400c04fd430c8]");
+    assert_eq!(format!("[{:>38}]", uuid.to_str()), "[  6ba7b810-9dad-11d1-80b4-00c04fd430c8]");
+}
+
+#[test]
+fn check_format_adapters() {
+    let uuid = lolid::NAMESPACE_DNS;
+    assert_eq!(uuid.hyphenated().to_string(), "6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+    assert_eq!(uuid.simple().to_string(), "6ba7b8109dad11d180b400c04fd430c8");
+    assert_eq!(uuid.braced().to_string(), "{6ba7b810-9dad-11d1-80b4-00c04fd430c8}");
+    assert_eq!(uuid.urn().to_string(), "urn:uuid:6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+    assert_eq!(format!("{:?}", uuid.simple()), "6ba7b8109dad11d180b400c04fd430c8");
+    assert_eq!(format!("[{:>34}]", uuid.simple()), "[  6ba7b8109dad11d180b400c04fd430c8]");
+    assert_eq!(Uuid::from(uuid.urn()), uuid);
+    assert_eq!(lolid::format::Braced::from(uuid).into_uuid(), uuid);
+}
+
+#[test]
+fn check_encode_to() {
+    let uuid = lolid::NAMESPACE_X500;
+    let mut frame = [b'#'; 40];
+
+    assert_eq!(uuid.encode_to(&mut frame[..35]), Err(lolid::EncodeError { required: 36 }));
+    assert_eq!(uuid.encode_to(&mut frame[2..]).unwrap(), "6ba7b814-9dad-11d1-80b4-00c04fd430c8");
+    assert_eq!(&frame[..], &b"##6ba7b814-9dad-11d1-80b4-00c04fd430c8##"[..]);
+
+    assert_eq!(uuid.encode_simple_to(&mut frame[..31]), Err(lolid::EncodeError { required: 32 }));
+    assert_eq!(uuid.encode_simple_to(&mut frame).unwrap(), "6ba7b8149dad11d180b400c04fd430c8");
+    assert_eq!(lolid::EncodeError { required: 32 }.to_string(), "Buffer is too small, required 32 bytes");
+}
+
+#[test]
+fn check_write_to() {
+    let mut out = String::from("id=");
+    lolid::NAMESPACE_DNS.write_to(&mut out).unwrap();
+    assert_eq!(out, "id=6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+
+    out.clear();
+    Uuid::nil().write_to(&mut out as &mut dyn core::fmt::Write).unwrap();
+    assert_eq!(out, "00000000-0000-0000-0000-000000000000");
+}
+
+#[test]
+fn check_base64() {
+    const UUID: Uuid = Uuid::from_bytes([0xfb, 0xef, 0xbe, 0xfb, 0xef, 0xbe, 0xfb, 0xef, 0xbe, 0xfb, 0xef, 0xbe, 0xfb, 0xef, 0xbe, 0xff]);
+    assert_eq!(UUID.to_base64(), "++++++++++++++++++++/w");
+    assert_eq!(UUID.to_base64url(), "--------------------_w");
+    assert_eq!(Uuid::parse_base64("++++++++++++++++++++/w"), Ok(UUID));
+    assert_eq!(Uuid::parse_base64url("--------------------_w=="), Ok(UUID));
+
+    let uuid = lolid::NAMESPACE_DNS;
+    assert_eq!(uuid.to_base64(), "a6e4EJ2tEdGAtADAT9QwyA");
+    assert_eq!(Uuid::parse_base64(&uuid.to_base64()), Ok(uuid));
+    assert_eq!(Uuid::parse_base64url(&uuid.to_base64url()), Ok(uuid));
+    assert_eq!(Uuid::nil().to_base64(), "AAAAAAAAAAAAAAAAAAAAAA");
+    assert_eq!(Uuid::max().to_base64url(), "_____________________w");
+
+    assert_eq!(Uuid::parse_base64("a6e4EJ2tEdGAtADAT9Qwy"), Err(lolid::DecodeError::InvalidLength(21)));
+    assert_eq!(Uuid::parse_base64("a6e4EJ2tEdGAtADAT9QwyA="), Err(lolid::DecodeError::InvalidLength(23)));
+    assert_eq!(Uuid::parse_base64("a6e4EJ2tEdGAtADAT9QwyB"), Err(lolid::DecodeError::InvalidDigit(b'B', 21)));
+    assert_eq!(Uuid::parse_base64("--------------------_w"), Ok(UUID));
+    assert_eq!(Uuid::parse_base64("--------------------_w=="), Ok(UUID));
+    assert_eq!(Uuid::parse_base64(&uuid.to_base64url()), Ok(uuid));
+    assert_eq!(Uuid::parse_base64("a6e4EJ2tEdGAtADAT9Qwy."), Err(lolid::DecodeError::InvalidDigit(b'.', 21)));
+    assert_eq!(Uuid::parse_base64url("++++++++++++++++++++/w"), Err(lolid::DecodeError::InvalidDigit(b'+', 0)));
+}
+
+#[test]
+fn check_base58() {
+    const TEXT: lolid::TextRepr<22> = lolid::NAMESPACE_DNS.to_base58();
+    assert_eq!(TEXT, "EJ34kCVxxF9jHMKD4EgrAK");
+    assert_eq!(Uuid::parse_base58(&TEXT), Ok(lolid::NAMESPACE_DNS));
+    assert_eq!(Uuid::nil().to_base58(), "1111111111111111111111");
+    assert_eq!(Uuid::max().to_base58(), "YcVfxkQb6JRzqk5kF2tNLv");
+    assert_eq!(Uuid::parse_base58("YcVfxkQb6JRzqk5kF2tNLv"), Ok(Uuid::max()));
+
+    assert_eq!(Uuid::parse_base58("YcVfxkQb6JRzqk5kF2tNLw"), Err(lolid::DecodeError::Overflow(21)));
+    assert_eq!(Uuid::parse_base58("EJ34kCVxxF9jHMKD4EgrA"), Err(lolid::DecodeError::InvalidLength(21)));
+    assert_eq!(Uuid::parse_base58("EJ34kCVxxF9jHMKD4EgrA0"), Err(lolid::DecodeError::InvalidDigit(b'0', 21)));
+    assert_eq!(Uuid::parse_base58("EJ34kCVxxF9jHMKD4EgrAl"), Err(lolid::DecodeError::InvalidDigit(b'l', 21)));
+}
+
+#[test]
+fn check_text_repr_traits() {
+    use std::collections::{BTreeSet, HashSet};
+
+    let first = lolid::NAMESPACE_DNS.to_str();
+    let second = lolid::NAMESPACE_URL.to_str();
+    let copy = first;
+    assert_eq!(copy, first);
+    assert_ne!(first, second);
+    assert!(first < second);
+    assert_eq!(first.to_string(), "6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+
+    let hashed = [first, second, copy].iter().copied().collect::<HashSet<_>>();
+    assert_eq!(hashed.len(), 2);
+    let sorted = [second, first].iter().copied().collect::<BTreeSet<_>>();
+    assert_eq!(sorted.into_iter().collect::<Vec<_>>(), [first, second]);
+}
+
+#[test]
+fn check_text_repr_into_array() {
+    const TEXT: [u8; 36] = lolid::NAMESPACE_DNS.to_str().into_array();
+    assert_eq!(&TEXT, b"6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+
+    let oid = Uuid::nil().to_oid_str().into_array();
+    assert_eq!(&oid[..6], b"2.25.0");
+    assert!(oid[6..].iter().all(|byte| *byte == 0));
+}
+
+#[test]
+fn check_code_literals() {
+    let uuid = lolid::NAMESPACE_DNS;
+    assert_eq!(uuid.to_rust_literal(), "[0x6b, 0xa7, 0xb8, 0x10, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8]");
+    assert_eq!(uuid.to_c_initializer(), "{0x6b, 0xa7, 0xb8, 0x10, 0x9d, 0xad, 0x11, 0xd1, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8}");
+}
+
+#[test]
+fn check_registry_str() {
+    //IID_IUnknown
+    const UUID: Uuid = Uuid::from_bytes([0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46]);
+    assert_eq!(UUID.to_registry_str(), "{00000000-0000-0000-C000-000000000046}");
+    assert_eq!(lolid::NAMESPACE_DNS.to_registry_str(), "{6BA7B810-9DAD-11D1-80B4-00C04FD430C8}");
+}
+
+#[test]
+fn check_dotnet_str() {
+    use lolid::format::DotNet;
+
+    let uuid = lolid::NAMESPACE_DNS;
+    assert_eq!(uuid.to_dotnet_str(DotNet::N), "6ba7b8109dad11d180b400c04fd430c8");
+    assert_eq!(uuid.to_dotnet_str(DotNet::D), "6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+    assert_eq!(uuid.to_dotnet_str(DotNet::B), "{6ba7b810-9dad-11d1-80b4-00c04fd430c8}");
+    assert_eq!(uuid.to_dotnet_str(DotNet::P), "(6ba7b810-9dad-11d1-80b4-00c04fd430c8)");
+    assert_eq!(uuid.to_dotnet_str(DotNet::X), "{0x6ba7b810,0x9dad,0x11d1,{0x80,0xb4,0x00,0xc0,0x4f,0xd4,0x30,0xc8}}");
+
+    assert_eq!(DotNet::from_specifier('x'), Some(DotNet::X));
+    assert_eq!(DotNet::from_specifier('B'), Some(DotNet::B));
+    assert_eq!(DotNet::from_specifier('G'), None);
+}
+
+#[test]
+fn check_parse_braced() {
+    const UUID: Result<Uuid, lolid::ParseError> = Uuid::parse_str("{6ba7b810-9dad-11d1-80b4-00c04fd430c8}");
+    assert_eq!(UUID, Ok(lolid::NAMESPACE_DNS));
+    assert_eq!(Uuid::parse_str(&lolid::NAMESPACE_URL.to_braced_str()), Ok(lolid::NAMESPACE_URL));
+
+    let err = Uuid::parse_str("{6ba7b810-9dad-11d1-80b4-00c04fd430c8)").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b')', 37));
+    let err = Uuid::parse_str("(6ba7b810-9dad-11d1-80b4-00c04fd430c8}").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b'(', 0));
+    let err = Uuid::parse_str("{6ba7b810-9dad-11d1-80b4-00c04fd430c,}").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b',', 36));
+    let err = Uuid::parse_str("{6ba7b810-9dad-11d1-80b4-00c04fd430c8").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidLength(37));
+    let err = Uuid::parse_str("{6ba7b8109dad11d180b400c04fd430c8}").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidLength(34));
+
+    let err = Uuid::parse_str("{6ba7b810-9dadg11d1-80b4-00c04fd430c8}").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b'g', 14));
+    let err = Uuid::parse_str("{6ba7b810-9dad1-1d1-80b4-00c04fd430c8}").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b'1', 14));
+    assert_eq!(err.expected(), lolid::Expected::Separator);
+    let err = Uuid::parse_str("{6ba7b810-9da-d11d1-80b4-00c04fd430c8}").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b'-', 13));
+    assert_eq!(err.expected(), lolid::Expected::HexDigit);
+}
+
+#[test]
+fn check_parse_urn() {
+    const UUID: Result<Uuid, lolid::ParseError> = Uuid::parse_str("urn:uuid:6ba7b811-9dad-11d1-80b4-00c04fd430c8");
+    assert_eq!(UUID, Ok(lolid::NAMESPACE_URL));
+    assert_eq!(Uuid::parse_str("URN:UUID:6BA7B811-9DAD-11D1-80B4-00C04FD430C8"), Ok(lolid::NAMESPACE_URL));
+    assert_eq!(Uuid::parse_str(&lolid::NAMESPACE_DNS.to_urn_str()), Ok(lolid::NAMESPACE_DNS));
+
+    let err = Uuid::parse_str("urn:guid:6ba7b811-9dad-11d1-80b4-00c04fd430c8").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b'g', 4));
+    let err = Uuid::parse_str("urn:uuid:6ba7b811-9dad-11d1-80b4-00c04fd430c,").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b',', 44));
+    let err = Uuid::parse_str("urn:uuid:6ba7b811-9dad-11d1g80b4-00c04fd430c8").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b'g', 27));
+    assert_eq!(err.position(), Some(27));
+    let err = Uuid::parse_str("urn:uuid:6ba7b811-9dad-11d1-80b4-00c04f-430c8").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b'-', 39));
+}
+
+#[test]
+fn check_parse_any() {
+    const UUID: Uuid = lolid::NAMESPACE_DNS;
+    let inputs = [
+        "6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+        "6BA7B810-9dad-11D1-80b4-00C04FD430C8",
+        "6ba7b8109dad11d180b400c04fd430c8",
+        "{6ba7b810-9dad-11d1-80b4-00c04fd430c8}",
+        "{6ba7b8109dad11d180b400c04fd430c8}",
+        "(6ba7b810-9dad-11d1-80b4-00c04fd430c8)",
+        "(6BA7B8109DAD11D180B400C04FD430C8)",
+        "urn:uuid:6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+        "Urn:Uuid:6ba7b8109dad11d180b400c04fd430c8",
+    ];
+    for input in inputs.iter() {
+        assert_eq!(Uuid::parse_any(input), Ok(UUID), "{}", input);
+    }
+
+    let err = Uuid::parse_any("{6ba7b810-9dad-11d1-80b4-00c04fd430c8)").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b')', 37));
+    let err = Uuid::parse_any("{{6ba7b810-9dad-11d1-80b4-00c04fd430c8}}").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidLength(40));
+    let err = Uuid::parse_any("(6ba7b8109dad11d180b400c04fd430c,)").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b',', 32));
+    let err = Uuid::parse_any("urn:uuid:6ba7b8109dad11d180b400c04fd430c").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidLength(40));
+}
+
+#[test]
+fn check_parse_dotnet_x() {
+    use lolid::ParseError;
+
+    const UUID: Result<Uuid, ParseError> = Uuid::parse_dotnet_x("{0x6ba7b810,0x9dad,0x11d1,{0x80,0xb4,0x00,0xc0,0x4f,0xd4,0x30,0xc8}}");
+    assert_eq!(UUID, Ok(lolid::NAMESPACE_DNS));
+    let text = lolid::NAMESPACE_URL.to_dotnet_str(lolid::format::DotNet::X);
+    assert_eq!(Uuid::parse_dotnet_x(&text), Ok(lolid::NAMESPACE_URL));
+    let header = "{ 0x6BA7B810, 0x9DAD, 0x11D1, { 0x80, 0xB4, 0x0, 0xC0, 0x4F, 0xD4, 0x30, 0xC8 } }";
+    assert_eq!(Uuid::parse_dotnet_x(header), Ok(lolid::NAMESPACE_DNS));
+
+    let err = Uuid::parse_dotnet_x("{0x6ba7b810,0x9dad,0x11d1,{0x80,0xb4,0x00,0xc0,0x4f,0xd4,0x30,0xc8}").unwrap_err();
+    assert_eq!(err, ParseError::InvalidLength(67));
+    let err = Uuid::parse_dotnet_x("{0x6ba7b8100,0x9dad,0x11d1,{0x80,0xb4,0x00,0xc0,0x4f,0xd4,0x30,0xc8}}").unwrap_err();
+    assert_eq!(err, ParseError::InvalidByte(b'0', 11));
+    let err = Uuid::parse_dotnet_x("{0x6ba7b810,0x9dad,0x11d1,{0x80,0xb4,0x00,0xc0,0x4f,0xd4,0x30}}").unwrap_err();
+    assert_eq!(err, ParseError::InvalidByte(b'}', 61));
+    let err = Uuid::parse_dotnet_x("{0x6ba7b810,0x,0x11d1,{0x80,0xb4,0x00,0xc0,0x4f,0xd4,0x30,0xc8}}").unwrap_err();
+    assert_eq!(err, ParseError::InvalidByte(b',', 14));
+    let err = Uuid::parse_dotnet_x("{0x6ba7b810,0x9dad,0x11d1,{0x80,0xb4,0x00,0xc0,0x4f,0xd4,0x30,0xc8}}}").unwrap_err();
+    assert_eq!(err, ParseError::InvalidByte(b'}', 68));
+}
+
+#[test]
+fn check_parse_fixed() {
+    const UUID: Result<Uuid, lolid::ParseError> = Uuid::parse_fixed(b"6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+    assert_eq!(UUID, Ok(lolid::NAMESPACE_DNS));
+    assert_eq!(Uuid::parse_fixed_simple(b"6ba7b8109dad11d180b400c04fd430c8"), Ok(lolid::NAMESPACE_DNS));
+    assert_eq!(Uuid::parse_fixed(&lolid::NAMESPACE_URL.to_str().into_array()), Ok(lolid::NAMESPACE_URL));
+
+    let err = Uuid::parse_fixed(b"6ba7b810-9dad-11d1-80b4-00c04fd430c,").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b',', 35));
+    let err = Uuid::parse_fixed(b"6ba7b8109dad-11d1-80b4-00c04fd430c8-").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidGroupLen(1, 12));
+    let err = Uuid::parse_fixed_simple(b"6ba7b8109dad11d180b400c04fd430cg").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b'g', 31));
+}
+
+#[test]
+fn check_try_from() {
+    use core::convert::TryFrom;
+
+    let bytes: [u8; 16] = lolid::NAMESPACE_DNS.into();
+    assert_eq!(Uuid::try_from("6ba7b810-9dad-11d1-80b4-00c04fd430c8"), Ok(lolid::NAMESPACE_DNS));
+    assert_eq!(Uuid::try_from("6ba7b810"), Err(lolid::ParseError::InvalidLength(8)));
+    assert_eq!(Uuid::try_from(&bytes[..]), Ok(lolid::NAMESPACE_DNS));
+    assert_eq!(Uuid::try_from(&bytes[1..]), Err(lolid::ParseError::InvalidLength(15)));
+    assert_eq!(Uuid::from(&bytes), lolid::NAMESPACE_DNS);
+}
+
+#[test]
+fn check_parse_many() {
+    let mut out = [Uuid::nil(); 4];
+    let input = b"6ba7b810-9dad-11d1-80b4-00c04fd430c8\n6ba7b8119dad11d180b400c04fd430c8\n{6ba7b812-9dad-11d1-80b4-00c04fd430c8}\n";
+    assert_eq!(Uuid::parse_many(input, b'\n', &mut out), Ok(3));
+    assert_eq!(out, [lolid::NAMESPACE_DNS, lolid::NAMESPACE_URL, lolid::NAMESPACE_OID, Uuid::nil()]);
+
+    let mut out = [Uuid::nil(); 3];
+    assert_eq!(Uuid::parse_many(&input[..input.len() - 1], b'\n', &mut out), Ok(3));
+    assert_eq!(out, [lolid::NAMESPACE_DNS, lolid::NAMESPACE_URL, lolid::NAMESPACE_OID]);
+
+    let mut out = [Uuid::nil(); 1];
+    assert_eq!(Uuid::parse_many(input, b'\n', &mut out), Err(lolid::ParseError::InvalidByte(b'\n', 36)));
+    let mut out: [Uuid; 0] = [];
+    assert_eq!(Uuid::parse_many(input, b'\n', &mut out), Err(lolid::ParseError::InvalidLength(input.len())));
+
+    let mut out = [Uuid::nil(); 4];
+    assert_eq!(Uuid::parse_many(b"", b',', &mut out), Ok(0));
+    let err = Uuid::parse_many(b"6ba7b8109dad11d180b400c04fd430c8,6ba7b8109dad11d180b400c04fd430cg,", b',', &mut out).unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b'g', 64));
+    let err = Uuid::parse_many(b"6ba7b8109dad11d180b400c04fd430c8,,6ba7b8109dad11d180b400c04fd430c8", b',', &mut out).unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b',', 33));
+    let err = Uuid::parse_many(b"6ba7b8109dad11d180b400c04fd430c8,{6ba7b810-9dad-11d1g80b4-00c04fd430c8}", b',', &mut out).unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b'g', 52));
+    let err = Uuid::parse_many(b"6ba7b8109dad11d180b400c04fd430c,6ba7b8109dad11d180b400c04fd430c8", b',', &mut out).unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b',', 31));
+    let err = Uuid::parse_many(b"6ba7b8109dad11d180b400c04fd430c8,6ba7b8109dad11d180b400c04fd430c", b',', &mut out).unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidLength(64));
+}
+
+#[test]
+fn check_base64_dotnet() {
+    //Convert.ToBase64String(new Guid("6ba7b810-9dad-11d1-80b4-00c04fd430c8").ToByteArray())
+    let uuid = Uuid::parse_base64("ELina62d0RGAtADAT9QwyA==").unwrap();
+    assert_eq!(Uuid::from_bytes_le(uuid.into()), lolid::NAMESPACE_DNS);
+    assert_eq!(Uuid::from_bytes(lolid::NAMESPACE_DNS.to_bytes_le()), uuid);
+    assert_eq!(Uuid::from_bytes_le(lolid::NAMESPACE_DNS.to_bytes_le()), lolid::NAMESPACE_DNS);
+}
+
+#[test]
+fn check_base32() {
+    use lolid::DecodeError;
+
+    const TEXT: lolid::TextRepr<26> = lolid::NAMESPACE_DNS.to_base32();
+    assert_eq!(TEXT, "3BMYW117DD278R1D00R17X8C68");
+    assert_eq!(Uuid::parse_base32(&TEXT), Ok(lolid::NAMESPACE_DNS));
+    assert_eq!(Uuid::parse_base32("3bmywIl7dd278r1dOOr17x8c68"), Ok(lolid::NAMESPACE_DNS));
+    assert_eq!(Uuid::nil().to_base32(), "00000000000000000000000000");
+    assert_eq!(Uuid::max().to_base32(), "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+    assert_eq!(Uuid::parse_base32("7ZZZZZZZZZZZZZZZZZZZZZZZZZ"), Ok(Uuid::max()));
+
+    assert_eq!(Uuid::parse_base32("8ZZZZZZZZZZZZZZZZZZZZZZZZZ"), Err(DecodeError::InvalidDigit(b'8', 0)));
+    assert_eq!(Uuid::parse_base32("3BMYW117DD278R1D00R17X8C6U"), Err(DecodeError::InvalidDigit(b'U', 25)));
+    assert_eq!(Uuid::parse_base32("3BMYW117DD278R1D00R17X8C6"), Err(DecodeError::InvalidLength(25)));
+
+    let err = Uuid::parse_base58("EJ34kCVxxF9jHMKD4EgrA0").unwrap_err();
+    assert_eq!(err.position(), Some(21));
+    assert_eq!(err.to_string(), "Invalid digit '30' at position 21");
+}
+
+#[test]
+fn check_is_valid() {
+    assert!(Uuid::is_valid("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a"));
+    assert!(Uuid::is_valid("60ECB7B6BA345AADA9EF9020B1EA210A"));
+    assert!(Uuid::is_valid("{60ecb7b6-ba34-5aad-a9ef-9020b1ea210a}"));
+    assert!(Uuid::is_valid("URN:uuid:60ecb7b6-ba34-5aad-a9ef-9020b1ea210a"));
+    assert!(Uuid::is_valid_bytes(b"60ecb7b6ba345aada9ef9020b1ea210a"));
+
+    assert!(!Uuid::is_valid(""));
+    assert!(!Uuid::is_valid("60ecb7b6-ba34-5aad-a9ef-9020b1ea210g"));
+    assert!(!Uuid::is_valid("60ecb7b6-ba345aad-a9ef-9020b1ea210a0"));
+    assert!(!Uuid::is_valid("60ecb7b6-ba34-5aad-a9ef-9020b1ea210a}"));
+    assert!(!Uuid::is_valid("{60ecb7b6-ba34-5aad-a9ef-9020b1ea210a)"));
+    assert!(!Uuid::is_valid("urn:uid::60ecb7b6-ba34-5aad-a9ef-9020b1ea210a"));
+    assert!(!Uuid::is_valid_bytes(b"60ecb7b6ba345aada9ef9020b1ea210"));
+
+    for text in ["60ecb7b6-ba34-5aad-a9ef-9020b1ea210a", "60ecb7b6-ba3,-5aad-a9ef-9020b1ea210a", "urn:uuid:60ecb7b6ba345aada9ef9020b1ea210a"] {
+        assert_eq!(Uuid::is_valid(text), Uuid::parse_str(text).is_ok());
+    }
+}
+
+#[test]
+fn check_parse_error_group() {
+    let err = Uuid::parse_str("60ecb7b6-ba34-5aa,-a9ef-9020b1ea210a").unwrap_err();
+    assert_eq!(err, lolid::ParseError::InvalidByte(b',', 17));
+    assert_eq!(err.group(), Some(3));
+
+    let err = Uuid::parse_str(",0ecb7b6-ba34-5aad-a9ef-9020b1ea210a").unwrap_err();
+    assert_eq!(err.group(), Some(1));
+
+    let err = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-9020b1ea210,").unwrap_err();
+    assert_eq!(err.group(), Some(5));
+
+    let err = Uuid::parse_str("60ecb7b6-ba34-5aad-a9ef-,020b1ea210a").unwrap_err();
+    assert_eq!(err.group(), Some(5));
+
+    let err = Uuid::parse_str("60ecb7b6-ba345-aad-a9ef-9020b1ea210a").unwrap_err();
+    assert_eq!(err.group(), Some(2));
+    assert_eq!(err.to_string(), "Group 2 has unexpected length 5");
+
+    let err = Uuid::parse_str("60ecb7b6-ba34-5aadga9ef-9020b1ea210a").unwrap_err();
+    assert_eq!(err.group(), Some(3));
+    assert_eq!(err.to_string(), "Group 3 is not followed by separator");
+
+    let err = Uuid::parse_str("60ecb7b6").unwrap_err();
+    assert_eq!(err.group(), None);
+}
+
+#[test]
+fn check_parse_trimmed() {
+    const EXPECTED: Uuid = lolid::NAMESPACE_DNS;
+
+    assert_eq!(Uuid::parse_trimmed("6ba7b810-9dad-11d1-80b4-00c04fd430c8"), Ok(EXPECTED));
+    assert_eq!(Uuid::parse_trimmed(" \t6ba7b810-9dad-11d1-80b4-00c04fd430c8\r\n"), Ok(EXPECTED));
+    assert_eq!(Uuid::parse_trimmed("\"6ba7b8109dad11d180b400c04fd430c8\""), Ok(EXPECTED));
+    assert_eq!(Uuid::parse_trimmed("  '{6ba7b810-9dad-11d1-80b4-00c04fd430c8}' "), Ok(EXPECTED));
+
+    assert_eq!(Uuid::parse_trimmed("\"6ba7b810-9dad-11d1-80b4-00c04fd430c8'"), Err(lolid::ParseError::InvalidByte(b'"', 0)));
+    assert_eq!(Uuid::parse_trimmed("\" 6ba7b810-9dad-11d1-80b4-00c04fd430c8\""), Err(lolid::ParseError::InvalidLength(39)));
+    assert_eq!(Uuid::parse_trimmed("  '6ba7b810-9dad-11d1-80b4-00c04fd430c,'"), Err(lolid::ParseError::InvalidByte(b',', 38)));
+    assert_eq!(Uuid::parse_trimmed("\"\""), Err(lolid::ParseError::InvalidLength(2)));
+    assert_eq!(Uuid::parse_trimmed("   "), Err(lolid::ParseError::InvalidLength(3)));
+}
+
+#[test]
+fn check_parse_guid_str() {
+    const GUID: Result<Uuid, lolid::ParseError> = Uuid::parse_guid_str("6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+    let guid = GUID.unwrap();
+
+    assert_eq!(guid.bytes(), [0x10, 0xb8, 0xa7, 0x6b, 0xad, 0x9d, 0xd1, 0x11, 0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8]);
+    assert_eq!(guid.bytes(), lolid::NAMESPACE_DNS.to_bytes_le());
+    assert_eq!(Uuid::from_bytes_le(guid.bytes()), lolid::NAMESPACE_DNS);
+    assert_eq!(Uuid::parse_guid_str("{6BA7B810-9DAD-11D1-80B4-00C04FD430C8}"), Ok(guid));
+    assert_eq!(Uuid::parse_guid_str("6ba7b810-9dad-11d1-80b4-00c04fd430c"), Err(lolid::ParseError::InvalidLength(35)));
+}
+
+#[test]
+fn check_uuid_macro() {
+    const DNS: Uuid = lolid::uuid!("6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+    assert_eq!(DNS, lolid::NAMESPACE_DNS);
+    assert_eq!(lolid::uuid!("{6BA7B810-9DAD-11D1-80B4-00C04FD430C8}"), lolid::NAMESPACE_DNS);
+    assert_eq!(lolid::uuid!("urn:uuid:6ba7b811-9dad-11d1-80b4-00c04fd430c8"), lolid::NAMESPACE_URL);
+}
+
+#[test]
+fn check_parse_utf16() {
+    let text = "{6ba7b810-9dad-11d1-80b4-00c04fd430c8}".encode_utf16().collect::<Vec<_>>();
+    assert_eq!(Uuid::parse_utf16(&text), Ok(lolid::NAMESPACE_DNS));
+
+    let text = "6BA7B8109DAD11D180B400C04FD430C8".encode_utf16().collect::<Vec<_>>();
+    assert_eq!(Uuid::parse_utf16(&text), Ok(lolid::NAMESPACE_DNS));
+
+    let text = "urn:uuid:6ba7b810-9dad-11d1-80b4-00c04fd430c8".encode_utf16().collect::<Vec<_>>();
+    assert_eq!(Uuid::parse_utf16(&text), Ok(lolid::NAMESPACE_DNS));
+
+    let text = "6ba7b810-9dad-11d1-80b4-00c04fd430cё".encode_utf16().collect::<Vec<_>>();
+    assert_eq!(Uuid::parse_utf16(&text), Err(lolid::ParseError::InvalidByte(0xff, 35)));
+
+    //Non ASCII code unit must not be truncated into valid digit
+    let mut text = "6ba7b810-9dad-11d1-80b4-00c04fd430c8".encode_utf16().collect::<Vec<_>>();
+    text[0] = 0x0136;
+    assert_eq!(Uuid::parse_utf16(&text), Err(lolid::ParseError::InvalidByte(0xff, 0)));
+
+    let text = "urn:uuid:{6ba7b810-9dad-11d1-80b4-00c04fd430c8}".encode_utf16().collect::<Vec<_>>();
+    assert_eq!(Uuid::parse_utf16(&text), Err(lolid::ParseError::InvalidLength(47)));
+    assert_eq!(Uuid::parse_utf16(&[]), Err(lolid::ParseError::InvalidLength(0)));
+}